@@ -0,0 +1,76 @@
+//! Compares the eager [`TableauBuilder`] with the lazy [`TableauExpander`].
+//!
+//! Run with `cargo bench --bench tableau`.
+
+use std::time::{Duration, Instant};
+
+use dare::{Expression, Parser, Tableau, TableauBuilder, TableauExpander};
+
+/// Returns `x & (a0 | b0) & ... & (an | bn) & ~x` if `closed`, otherwise
+/// the same formula without `~x`.
+fn formula(n: usize, closed: bool) -> Expression {
+    let mut source = String::from("x");
+
+    for i in 0..n {
+        source += &format!(" & (a{} | b{})", i, i);
+    }
+
+    if closed {
+        source += " & ~x";
+    }
+
+    Parser::new().parse(&source).unwrap()
+}
+
+fn size(tableau: &Tableau) -> usize {
+    tableau.expectations.len()
+        + tableau
+            .branches
+            .iter()
+            .map(|branch| size(branch))
+            .sum::<usize>()
+}
+
+fn measure(f: impl Fn() -> Tableau) -> (usize, Duration) {
+    const RUNS: u32 = 5;
+
+    let start = Instant::now();
+    let mut tableau = f();
+    for _ in 1..RUNS {
+        tableau = f();
+    }
+
+    (size(&tableau), start.elapsed() / RUNS)
+}
+
+fn main() {
+    for closed in [true, false] {
+        println!(
+            "{} formulas",
+            if closed {
+                "unsatisfiable"
+            } else {
+                "satisfiable"
+            }
+        );
+        println!(
+            "{:>3} | {:>10} | {:>12} | {:>10} | {:>12}",
+            "n", "eager rows", "eager time", "lazy rows", "lazy time"
+        );
+
+        for n in (2..=12).step_by(2) {
+            let expr = formula(n, closed);
+
+            let (eager_size, eager_time) =
+                measure(|| TableauBuilder::default().build_expression(&expr, true));
+            let (lazy_size, lazy_time) = measure(|| TableauExpander::default().expand(&expr, true));
+
+            println!(
+                "{:>3} | {:>10} | {:>12?} | {:>10} | {:>12?}",
+                n, eager_size, eager_time, lazy_size, lazy_time
+            );
+        }
+
+        println!();
+    }
+}
@@ -1,10 +1,68 @@
 use std::path::PathBuf;
 
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args as ClapArgs, Parser, Subcommand, ValueEnum};
 
 #[derive(ValueEnum, Clone, Debug)]
 pub enum OutputFormat {
+    Text,
+    Markdown,
+    Csv,
     Latex,
+    /// LaTeX using the `forest` package for the layout.
+    Forest,
+    /// Graphviz DOT, render with e.g. `dot -Tsvg`.
+    Dot,
+    /// A standalone SVG image.
+    Svg,
+    /// HTML with MathML for the expressions.
+    Html,
+}
+
+#[derive(ValueEnum, Clone, Debug)]
+pub enum PrecedenceTable {
+    /// Every binary operator has its own precedence.
+    Wikipedia,
+    /// Conjunction, disjunction and exclusive disjunction share the same precedence.
+    Traditional,
+}
+
+#[derive(ValueEnum, Clone, Debug)]
+pub enum InputFormat {
+    /// Logical expressions, definitions and entailments.
+    Text,
+    /// A DIMACS CNF problem, variables are named `x1`, `x2`, ... unless `c var <variable> <name>` comments name them.
+    Dimacs,
+}
+
+#[derive(ValueEnum, Clone, Debug)]
+pub enum Engine {
+    /// Expand a tableau, solutions only assign the variables they need.
+    Tableau,
+    /// Run the built-in CDCL SAT solver on the Tseitin encoding.
+    Sat,
+}
+
+#[derive(ValueEnum, Clone, Debug)]
+pub enum NormalForm {
+    /// Negation normal form, negations only apply to atomic propositions.
+    Nnf,
+    /// Conjunctive normal form, a conjunction of disjunctions.
+    Cnf,
+    /// Disjunctive normal form, a disjunction of conjunctions.
+    Dnf,
+    /// An equisatisfiable conjunctive normal form with auxiliary variables, printed as DIMACS.
+    Tseitin,
+}
+
+#[derive(ClapArgs, Debug)]
+pub struct ParserOptions {
+    /// Read chains of operators with equal precedence from left to right instead of requiring parentheses.
+    #[clap(long)]
+    pub non_strict: bool,
+
+    /// The precedence table used to order binary operators.
+    #[clap(long, value_enum, default_value = "wikipedia")]
+    pub precedence: PrecedenceTable,
 }
 
 #[derive(Parser, Debug)]
@@ -21,10 +79,40 @@ pub struct Tableau {
     #[clap(short, long)]
     pub show_all_ids: bool,
 
+    /// Print the tableau once more after every rule application.
+    #[clap(long)]
+    pub steps: bool,
+
     /// Copy the output to the clipboard.
     #[clap(short, long)]
     pub clip_board: bool,
 
+    #[clap(flatten)]
+    pub parser: ParserOptions,
+
+    /// If this is used and source isn't supplied, the expression will be read path.
+    #[clap(short, long)]
+    pub path: Option<PathBuf>,
+
+    /// The logical expression to evaluate.
+    ///
+    /// If not provided, the expression will be read from stdin.
+    pub source: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub struct Table {
+    /// The output format.
+    #[clap(value_enum)]
+    pub format: OutputFormat,
+
+    /// Copy the output to the clipboard.
+    #[clap(short, long)]
+    pub clip_board: bool,
+
+    #[clap(flatten)]
+    pub parser: ParserOptions,
+
     /// If this is used and source isn't supplied, the expression will be read path.
     #[clap(short, long)]
     pub path: Option<PathBuf>,
@@ -37,10 +125,79 @@ pub struct Tableau {
 
 #[derive(Parser, Debug)]
 pub struct Solve {
-    /// Solve the given tableau when it evaluates to false.
+    /// The output format.
+    #[clap(long, value_enum, default_value = "text")]
+    pub format: OutputFormat,
+
+    /// The format of the source.
+    #[clap(long, value_enum, default_value = "text")]
+    pub input_format: InputFormat,
+
+    /// The procedure used to find the solutions.
+    #[clap(long, value_enum, default_value = "tableau")]
+    pub engine: Engine,
+
+    /// Solve the given tableau when it evaluates to false, not supported for entailments.
     #[clap(short = 'f', long = "false")]
     pub expect_false: bool,
 
+    /// Print every solution the sat engine finds instead of only the first.
+    #[clap(short, long)]
+    pub all: bool,
+
+    #[clap(flatten)]
+    pub parser: ParserOptions,
+
+    /// If this is used and source isn't supplied, the expression will be read path.
+    #[clap(short, long)]
+    pub path: Option<PathBuf>,
+
+    /// The logical expression to evaluate.
+    ///
+    /// If not provided, the expression will be read from stdin.
+    pub source: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub struct Ast {
+    /// The output format.
+    #[clap(value_enum)]
+    pub format: OutputFormat,
+
+    /// Copy the output to the clipboard.
+    #[clap(short, long)]
+    pub clip_board: bool,
+
+    #[clap(flatten)]
+    pub parser: ParserOptions,
+
+    /// If this is used and source isn't supplied, the expression will be read path.
+    #[clap(short, long)]
+    pub path: Option<PathBuf>,
+
+    /// The logical expression to evaluate.
+    ///
+    /// If not provided, the expression will be read from stdin.
+    pub source: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub struct Normalize {
+    /// The normal form to convert to.
+    #[clap(long, value_enum, default_value = "cnf")]
+    pub form: NormalForm,
+
+    /// The output format.
+    #[clap(long, value_enum, default_value = "text")]
+    pub format: OutputFormat,
+
+    /// Copy the output to the clipboard.
+    #[clap(short, long)]
+    pub clip_board: bool,
+
+    #[clap(flatten)]
+    pub parser: ParserOptions,
+
     /// If this is used and source isn't supplied, the expression will be read path.
     #[clap(short, long)]
     pub path: Option<PathBuf>,
@@ -140,8 +297,14 @@ pub struct Complete {
 pub enum SubCommand {
     /// Print the tableau for a given logical expression.
     Tableau(Tableau),
+    /// Print the truth table for a given logical expression.
+    Table(Table),
     /// Print the solutions for a given logical expression.
     Solve(Solve),
+    /// Print the syntax tree of a given logical expression.
+    Ast(Ast),
+    /// Print a given logical expression in a normal form.
+    Normalize(Normalize),
     /// Installs the completion script for the given shell.
     Complete(Complete),
 }
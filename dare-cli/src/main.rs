@@ -3,7 +3,7 @@ mod command;
 
 use std::{
     env, fs,
-    io::{self, Read},
+    io::{self, IsTerminal, Read},
     path::PathBuf,
 };
 
@@ -11,7 +11,11 @@ use clap::Parser;
 
 use self::clipboard::*;
 use command::*;
-use dare::{Solutions, TableauWriter};
+use dare::{
+    Associativity, Cnf, DiagnosticWriter, ErrorKind, Expression, ExpressionWriter, Interpreter,
+    ParserConfig, Precedence, Program, Solution, Solutions, SolutionsWriter, StatementKind,
+    TableauWriter, TruthTableWriter, Verdict,
+};
 
 impl Shell {
     #[cfg(target_os = "windows")]
@@ -207,30 +211,365 @@ where
     }
 }
 
+/// Prints every error in `errors` as a diagnostic for `source` and exits.
+pub fn report_errors(source: &str, errors: &[dare::Error]) -> ! {
+    let mut writer = DiagnosticWriter::new(source);
+
+    if io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none() {
+        writer.colored();
+    }
+
+    for error in errors {
+        writer.write_error(error);
+    }
+
+    eprint!("{}", writer.finalize());
+    std::process::exit(1);
+}
+
+/// Returns the value of `result` or reports its error as a diagnostic for `source`.
+pub fn report_error<T>(source: &str, result: Result<T, dare::Error>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(error) => report_errors(source, &[error]),
+    }
+}
+
+impl ParserOptions {
+    pub fn config(&self) -> ParserConfig {
+        ParserConfig {
+            associativity: if self.non_strict {
+                Associativity::NonStrict
+            } else {
+                Associativity::Strict
+            },
+            precedence: match self.precedence {
+                PrecedenceTable::Wikipedia => Precedence::Wikipedia,
+                PrecedenceTable::Traditional => Precedence::Traditional,
+            },
+        }
+    }
+}
+
+pub fn parse_program(source: &str, config: ParserConfig) -> Program {
+    let mut errors = Vec::new();
+    let program = dare::Parser::with_config(config).parse_program_with_errors(source, &mut errors);
+
+    if !errors.is_empty() {
+        report_errors(source, &errors);
+    }
+
+    program
+}
+
+/// Reads `source` as a DIMACS problem, returning a program of a single query.
+pub fn read_dimacs(source: &str) -> Program {
+    let mut reader = dare::DimacsReader::new();
+
+    match reader.read(source) {
+        Ok(expression) => Program {
+            statements: vec![dare::Statement {
+                span: expression.span,
+                kind: StatementKind::Expression(expression),
+            }],
+        },
+        Err(_) => report_errors(source, &reader.errors),
+    }
+}
+
+/// Parses `source` as a program, defining every definition and returning the
+/// remaining expressions.
+pub fn get_queries(source: &str, config: ParserConfig) -> (Interpreter, Vec<Expression>) {
+    let program = parse_program(source, config);
+
+    let mut interpreter = Interpreter::new();
+    let mut expressions = Vec::new();
+
+    for statement in program.statements {
+        match statement.kind {
+            StatementKind::Definition(definition) => {
+                // errors are collected by the interpreter
+                let _ = interpreter.define(&definition);
+            }
+            StatementKind::Expression(expression) => expressions.push(expression),
+            StatementKind::Entailment(_) => {
+                let error = dare::Error::new()
+                    .with_kind(ErrorKind::UnsupportedStatement)
+                    .with_msg("entailment queries are only supported by solve")
+                    .with_span(statement.span);
+                interpreter.errors.push(error);
+            }
+        }
+    }
+
+    if !interpreter.errors.is_empty() {
+        report_errors(source, &interpreter.errors);
+    }
+
+    (interpreter, expressions)
+}
+
+pub fn print_solution(index: usize, solution: &Solution) {
+    println!("Solution #{}", index);
+
+    for (variable, value) in solution.iter() {
+        let value = if value { "T" } else { "F" };
+        println!("\t{}: {}", variable, value);
+    }
+}
+
+pub fn print_solutions(solutions: &Solutions) {
+    if solutions.is_empty() {
+        println!("No solutions found.");
+    }
+
+    for (i, solution) in solutions.iter().enumerate() {
+        print_solution(i, solution);
+
+        if i < solutions.len() - 1 {
+            println!();
+        }
+    }
+}
+
+/// Prints the solutions of `cnf` as soon as the SAT solver finds them, only
+/// the first one unless `all` is set.
+pub fn print_cnf_solutions(cnf: &Cnf, all: bool, markdown: bool) {
+    let limit = if all { usize::MAX } else { 1 };
+    let mut found = false;
+
+    for (i, solution) in dare::CnfSolutions::new(cnf).take(limit).enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        if markdown {
+            println!("**Solution #{}**\n", i);
+
+            let mut writer = dare::MarkdownSolutionsWriter::default();
+            writer.write_solution(&solution);
+            print!("{}", writer.finalize());
+        } else {
+            print_solution(i, &solution);
+        }
+
+        found = true;
+    }
+
+    if !found {
+        println!("No solutions found.");
+    }
+}
+
+/// Describes a rule application, e.g. `conjunction rule on a & b : T`.
+pub fn describe_step(tableau: &dare::Tableau, step: &dare::TableauStep) -> String {
+    let value = if step.truth_value { "T" } else { "F" };
+
+    match tableau.expectation(step.expectation) {
+        Some(expectation) => format!("{} rule on {} : {}", step.rule, expectation.expr, value),
+        None => format!("{} rule", step.rule),
+    }
+}
+
+pub fn print_markdown_verdict(verdict: &Verdict) {
+    match verdict.model() {
+        Some(model) => {
+            println!("Not valid, counter-example:\n");
+
+            let mut markdown = dare::MarkdownSolutionsWriter::default();
+            markdown.write_solution(model);
+            print!("{}", markdown.finalize());
+        }
+        None => println!("Valid."),
+    }
+}
+
+pub fn print_verdict(verdict: &Verdict) {
+    match verdict.model() {
+        Some(model) => {
+            println!("Not valid, counter-example:");
+
+            for (variable, value) in model.iter() {
+                let value = if value { "T" } else { "F" };
+                println!("\t{}: {}", variable, value);
+            }
+        }
+        None => println!("Valid."),
+    }
+}
+
 fn main() {
     let args = Args::parse();
 
     match args.subcommand {
         SubCommand::Tableau(command) => {
             let source = get_source(command.source, command.path);
+            let (interpreter, expressions) = get_queries(&source, command.parser.config());
+
+            let mut outputs = Vec::new();
+            for expression in &expressions {
+                let expression = interpreter.expand(expression);
+                let expect = !command.expect_false;
+
+                // without --steps only the complete tableau is printed, so it's
+                // built by the lazy expander instead of the traced builder
+                let mut builder = dare::TableauBuilder::traced();
+                let tableau = if command.steps {
+                    builder.build_expression(&expression, expect)
+                } else {
+                    dare::TableauExpander::default().expand(&expression, expect)
+                };
+
+                let steps = builder.steps();
+                let snapshots = if command.steps {
+                    (0..=steps.len())
+                        .map(|count| tableau.after_steps(steps, count))
+                        .collect()
+                } else {
+                    vec![tableau.clone()]
+                };
+
+                let id_map = dare::SharedIdMap::default();
+                let mut blocks = Vec::new();
+
+                for (count, snapshot) in snapshots.iter().enumerate() {
+                    let (comment, block) = match command.format {
+                        OutputFormat::Latex => {
+                            let mut latex = dare::LatexTableauWriter::default();
+                            latex.share_ids(&id_map);
+
+                            if command.show_all_ids {
+                                latex.show_all_ids();
+                            }
+
+                            report_error(&source, latex.write_tableau(snapshot));
+                            (("% ", ""), latex.finalize())
+                        }
+                        OutputFormat::Forest => {
+                            let mut forest = dare::ForestTableauWriter::default();
+                            forest.share_ids(&id_map);
+
+                            if command.show_all_ids {
+                                forest.color_ids("red");
+                            }
+
+                            report_error(&source, forest.write_tableau(snapshot));
+                            (("% ", ""), forest.finalize())
+                        }
+                        OutputFormat::Text => {
+                            let mut text = dare::TextTableauWriter::default();
+                            text.share_ids(&id_map);
+
+                            report_error(&source, text.write_tableau(snapshot));
+                            (("", ""), text.finalize())
+                        }
+                        OutputFormat::Dot => {
+                            let mut dot = dare::DotTableauWriter::default();
+                            dot.share_ids(&id_map);
+
+                            report_error(&source, dot.write_tableau(snapshot));
+                            (("// ", ""), dot.finalize())
+                        }
+                        OutputFormat::Svg => {
+                            let mut svg = dare::SvgTableauWriter::default();
+                            svg.share_ids(&id_map);
+
+                            if command.show_all_ids {
+                                svg.show_all_ids();
+                            }
+
+                            report_error(&source, svg.write_tableau(snapshot));
+                            (("<!-- ", " -->"), svg.finalize())
+                        }
+                        OutputFormat::Html => {
+                            let mut html = dare::HtmlTableauWriter::default();
+                            html.share_ids(&id_map);
+
+                            report_error(&source, html.write_tableau(snapshot));
+                            (("<!-- ", " -->"), html.finalize())
+                        }
+                        format => {
+                            eprintln!(
+                                "Error: the {:?} format is not supported for tableaux",
+                                format
+                            );
+                            std::process::exit(1);
+                        }
+                    };
+
+                    // every step after the first is introduced by the rule that fired
+                    let block = match count.checked_sub(1).map(|i| &steps[i]) {
+                        Some(step) => format!(
+                            "{}Step {}: {}{}\n{}",
+                            comment.0,
+                            count,
+                            describe_step(&tableau, step),
+                            comment.1,
+                            block
+                        ),
+                        None => block,
+                    };
+                    blocks.push(block.trim_end().to_string());
+                }
+
+                outputs.push(blocks.join("\n\n"));
+            }
 
-            // TODO: proper error reporting
-            let tableau = dare::Tableau::parse(&source, !command.expect_false).unwrap();
+            let output = outputs.join("\n\n");
+            println!("{}", output);
+            if command.clip_board {
+                clipboard_set(output);
+            }
+        }
+        SubCommand::Table(command) => {
+            let source = get_source(command.source, command.path);
+            let (interpreter, expressions) = get_queries(&source, command.parser.config());
 
-            let output = match command.format {
-                OutputFormat::Latex => {
-                    let mut latex = dare::LatexTableauWriter::default();
+            let mut outputs = Vec::new();
+            for expression in &expressions {
+                let table = report_error(
+                    &source,
+                    dare::TruthTable::with_interpreter(&interpreter, expression),
+                );
 
-                    if command.show_all_ids {
-                        latex.show_all_ids();
+                outputs.push(match command.format {
+                    OutputFormat::Text => {
+                        let mut text = dare::TextTruthTableWriter::default();
+                        report_error(&source, text.write_truth_table(&table));
+                        text.finalize()
                     }
+                    OutputFormat::Markdown => {
+                        let mut markdown = dare::MarkdownTruthTableWriter::default();
+                        report_error(&source, markdown.write_truth_table(&table));
+                        markdown.finalize()
+                    }
+                    OutputFormat::Csv => {
+                        let mut csv = dare::CsvTruthTableWriter::default();
+                        report_error(&source, csv.write_truth_table(&table));
+                        csv.finalize()
+                    }
+                    OutputFormat::Latex => {
+                        let mut latex = dare::LatexTruthTableWriter::default();
+                        report_error(&source, latex.write_truth_table(&table));
+                        latex.finalize()
+                    }
+                    OutputFormat::Html => {
+                        let mut html = dare::HtmlTruthTableWriter::default();
+                        report_error(&source, html.write_truth_table(&table));
+                        html.finalize()
+                    }
+                    format => {
+                        eprintln!(
+                            "Error: the {:?} format is not supported for truth tables",
+                            format
+                        );
+                        std::process::exit(1);
+                    }
+                });
+            }
 
-                    latex.write_tableau(&tableau).unwrap();
-                    latex.finalize()
-                }
-            };
-
-            println!("{}", output);
+            let output = outputs.join("\n");
+            print!("{}", output);
             if command.clip_board {
                 clipboard_set(output);
             }
@@ -238,25 +577,228 @@ fn main() {
         SubCommand::Solve(command) => {
             let source = get_source(command.source, command.path);
 
-            let tableau = dare::Tableau::parse(&source, !command.expect_false).unwrap();
-            let mut solutions = Solutions::from(&tableau);
-            solutions.clean();
-
-            if solutions.is_empty() {
-                println!("No solutions found.");
+            if !matches!(command.format, OutputFormat::Text | OutputFormat::Markdown) {
+                eprintln!(
+                    "Error: the {:?} format is not supported for solutions",
+                    command.format
+                );
+                std::process::exit(1);
             }
+            let markdown = matches!(command.format, OutputFormat::Markdown);
+
+            // a DIMACS problem is already a CNF, which the solver takes as is
+            if let (InputFormat::Dimacs, Engine::Sat, false) =
+                (&command.input_format, &command.engine, command.expect_false)
+            {
+                let mut reader = dare::DimacsReader::new();
+                let cnf = match reader.read_cnf(&source) {
+                    Ok(cnf) => cnf,
+                    Err(_) => report_errors(&source, &reader.errors),
+                };
+
+                print_cnf_solutions(&cnf, command.all, markdown);
+                return;
+            }
+
+            let program = match command.input_format {
+                InputFormat::Text => parse_program(&source, command.parser.config()),
+                InputFormat::Dimacs => read_dimacs(&source),
+            };
 
-            for (i, solution) in solutions.iter().enumerate() {
-                println!("Solution #{}", i);
+            if command.expect_false {
+                let errors = program
+                    .statements
+                    .iter()
+                    .filter(|statement| matches!(statement.kind, StatementKind::Entailment(_)))
+                    .map(|statement| {
+                        dare::Error::new()
+                            .with_kind(ErrorKind::UnsupportedStatement)
+                            .with_msg("entailment queries can't be expected to be false")
+                            .with_span(statement.span)
+                            .with_help("leave out --false, invalid entailments are answered with a counter-example")
+                    })
+                    .collect::<Vec<_>>();
+
+                if !errors.is_empty() {
+                    report_errors(&source, &errors);
+                }
+            }
 
-                for (variable, value) in solution.iter() {
-                    let value = if value { "T" } else { "F" };
-                    println!("\t{}: {}", variable, value);
+            let queries = program
+                .statements
+                .iter()
+                .filter(|statement| !matches!(statement.kind, StatementKind::Definition(_)))
+                .count();
+
+            let mut interpreter = dare::Interpreter::new();
+            let mut first = true;
+            for statement in &program.statements {
+                match &statement.kind {
+                    StatementKind::Definition(definition) => {
+                        report_error(&source, interpreter.define(definition));
+                        continue;
+                    }
+                    StatementKind::Expression(_) | StatementKind::Entailment(_) => {}
                 }
 
-                if i < solutions.len() - 1 {
+                if !first {
                     println!();
                 }
+                first = false;
+
+                if queries > 1 && markdown {
+                    println!("`{}`\n", statement);
+                } else if queries > 1 {
+                    println!("{}", statement);
+                }
+
+                match &statement.kind {
+                    StatementKind::Expression(expression) => {
+                        let expression = interpreter.expand(expression);
+                        let solutions = match command.engine {
+                            Engine::Tableau => {
+                                let tableau = dare::TableauExpander::default()
+                                    .expand(&expression, !command.expect_false);
+                                let mut solutions = Solutions::from(&tableau);
+                                solutions.clean();
+                                solutions
+                            }
+                            Engine::Sat => {
+                                let mut cnf = Cnf::new();
+                                cnf.assert(&expression, !command.expect_false);
+                                print_cnf_solutions(&cnf, command.all, markdown);
+                                continue;
+                            }
+                        };
+
+                        if markdown {
+                            let mut writer = dare::MarkdownSolutionsWriter::default();
+                            report_error(&source, writer.write_solutions(&solutions));
+                            print!("{}", writer.finalize());
+                        } else {
+                            print_solutions(&solutions);
+                        }
+                    }
+                    StatementKind::Entailment(entailment) => {
+                        let premises = entailment
+                            .premises
+                            .iter()
+                            .map(|premise| interpreter.expand(premise))
+                            .collect::<Vec<_>>();
+                        let conclusion = interpreter.expand(&entailment.conclusion);
+
+                        let verdict = match command.engine {
+                            Engine::Tableau => dare::entails(&premises, &conclusion),
+                            Engine::Sat => dare::sat_entails(&premises, &conclusion),
+                        };
+                        if markdown {
+                            print_markdown_verdict(&verdict);
+                        } else {
+                            print_verdict(&verdict);
+                        }
+                    }
+                    StatementKind::Definition(_) => unreachable!(),
+                }
+            }
+        }
+        SubCommand::Ast(command) => {
+            let source = get_source(command.source, command.path);
+            let (interpreter, expressions) = get_queries(&source, command.parser.config());
+
+            let mut outputs = Vec::new();
+            for expression in &expressions {
+                let expression = interpreter.expand(expression);
+
+                outputs.push(match command.format {
+                    OutputFormat::Markdown => {
+                        let mut markdown = dare::MarkdownExpressionWriter::default();
+                        report_error(&source, markdown.write_expression(&expression));
+                        markdown.finalize() + "\n"
+                    }
+                    OutputFormat::Html => {
+                        let mut html = dare::HtmlExpressionWriter::default();
+                        report_error(&source, html.write_expression(&expression));
+                        html.finalize() + "\n"
+                    }
+                    OutputFormat::Dot => {
+                        let mut dot = dare::DotExpressionWriter::default();
+                        report_error(&source, dot.write_expression(&expression));
+                        dot.finalize()
+                    }
+                    format => {
+                        eprintln!(
+                            "Error: the {:?} format is not supported for syntax trees",
+                            format
+                        );
+                        std::process::exit(1);
+                    }
+                });
+            }
+
+            let output = outputs.join("\n");
+            print!("{}", output);
+            if command.clip_board {
+                clipboard_set(output);
+            }
+        }
+        SubCommand::Normalize(command) => {
+            let source = get_source(command.source, command.path);
+            let (interpreter, expressions) = get_queries(&source, command.parser.config());
+
+            let mut outputs = Vec::new();
+            for expression in &expressions {
+                let expression = interpreter.expand(expression);
+
+                if let NormalForm::Tseitin = command.form {
+                    if !matches!(command.format, OutputFormat::Text) {
+                        eprintln!("Error: the tseitin form can only be written as text");
+                        std::process::exit(1);
+                    }
+
+                    let mut dimacs = dare::DimacsWriter::default();
+                    dimacs.write_cnf(&dare::tseitin(&expression));
+                    outputs.push(dimacs.finalize().trim_end().to_string());
+                    continue;
+                }
+
+                let normalized = match command.form {
+                    NormalForm::Nnf => dare::to_nnf(&expression),
+                    NormalForm::Cnf => dare::to_cnf(&expression),
+                    NormalForm::Dnf => dare::to_dnf(&expression),
+                    NormalForm::Tseitin => unreachable!(),
+                };
+
+                outputs.push(match command.format {
+                    OutputFormat::Text => normalized.to_string(),
+                    OutputFormat::Markdown => {
+                        let mut markdown = dare::MarkdownExpressionWriter::default();
+                        report_error(&source, markdown.write_expression(&normalized));
+                        markdown.finalize()
+                    }
+                    OutputFormat::Latex => {
+                        let mut latex = dare::LatexExpressionWriter::default();
+                        report_error(&source, latex.write_expression(&normalized));
+                        latex.finalize()
+                    }
+                    OutputFormat::Html => {
+                        let mut html = dare::HtmlExpressionWriter::default();
+                        report_error(&source, html.write_expression(&normalized));
+                        html.finalize()
+                    }
+                    format => {
+                        eprintln!(
+                            "Error: the {:?} format is not supported for expressions",
+                            format
+                        );
+                        std::process::exit(1);
+                    }
+                });
+            }
+
+            let output = outputs.join("\n");
+            println!("{}", output);
+            if command.clip_board {
+                clipboard_set(output);
             }
         }
         SubCommand::Complete(command) => match command.subcommand {
@@ -105,3 +105,88 @@ impl Expression {
         }
     }
 }
+
+/// Binds a name to an expression, e.g. `p := q & r`.
+#[derive(Clone, Debug)]
+pub struct Definition {
+    pub name: AtomicExpression,
+    pub operator: &'static str,
+    pub expression: Expression,
+    pub operator_span: Span,
+    pub span: Span,
+}
+
+impl std::fmt::Display for Definition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.name, self.operator, self.expression)
+    }
+}
+
+/// Asks whether the premises entail the conclusion, e.g. `p -> q, p ⊨ q`.
+///
+/// Provability, e.g. `p -> q, p ⊢ q`, is parsed into the same statement, as
+/// tableaux prove exactly the valid entailments of propositional logic.
+#[derive(Clone, Debug)]
+pub struct Entailment {
+    pub premises: Vec<Expression>,
+    pub operator: &'static str,
+    pub conclusion: Expression,
+    pub operator_span: Span,
+}
+
+impl std::fmt::Display for Entailment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, premise) in self.premises.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", premise)?;
+        }
+
+        if !self.premises.is_empty() {
+            write!(f, " ")?;
+        }
+
+        write!(f, "{} {}", self.operator, self.conclusion)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum StatementKind {
+    Definition(Definition),
+    Expression(Expression),
+    Entailment(Entailment),
+}
+
+#[derive(Clone, Debug)]
+pub struct Statement {
+    pub kind: StatementKind,
+    pub span: Span,
+}
+
+impl std::fmt::Display for Statement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            StatementKind::Definition(definition) => definition.fmt(f),
+            StatementKind::Expression(expression) => expression.fmt(f),
+            StatementKind::Entailment(entailment) => entailment.fmt(f),
+        }
+    }
+}
+
+/// A sequence of [`Statement`]s separated by `;` or line breaks.
+#[derive(Clone, Debug, Default)]
+pub struct Program {
+    pub statements: Vec<Statement>,
+}
+
+impl std::fmt::Display for Program {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for statement in &self.statements {
+            writeln!(f, "{}", statement)?;
+        }
+
+        Ok(())
+    }
+}
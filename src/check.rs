@@ -0,0 +1,195 @@
+use crate::{Cnf, Expression, SatSolver, Solution, Solutions, TableauExpander};
+
+/// The answer to a question about one or more [`Expression`]s.
+///
+/// The [`model`](Verdict::model) is an assignment demonstrating the answer,
+/// a counter-model when a validity or equivalence check fails and a
+/// witness when an expression is satisfiable.
+///
+/// # Example
+/// ```rust
+/// # use dare::{is_valid, Parser};
+/// let expr = Parser::new().parse("a -> b").unwrap();
+/// let verdict = is_valid(&expr);
+///
+/// assert!(!verdict.holds());
+///
+/// let model = verdict.model().unwrap();
+/// assert_eq!(model.get("a"), Some(true));
+/// assert_eq!(model.get("b"), Some(false));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Verdict {
+    holds: bool,
+    model: Option<Solution>,
+}
+
+impl Verdict {
+    pub const fn new(holds: bool, model: Option<Solution>) -> Self {
+        Self { holds, model }
+    }
+
+    /// Returns `true` if the checked property holds.
+    pub const fn holds(&self) -> bool {
+        self.holds
+    }
+
+    /// Returns the assignment demonstrating the verdict, if there is one.
+    ///
+    /// Variables missing from the model may take any value.
+    pub fn model(&self) -> Option<&Solution> {
+        self.model.as_ref()
+    }
+}
+
+/// Returns the first assignment that makes `expr` evaluate to `expect`.
+fn find_model(expr: &Expression, expect: bool) -> Option<Solution> {
+    let tableau = TableauExpander::default().expand(expr, expect);
+    Solutions::from(&tableau).iter().next().cloned()
+}
+
+/// Checks whether some assignment makes `expr` true.
+///
+/// The model is a satisfying assignment.
+pub fn is_satisfiable(expr: &Expression) -> Verdict {
+    let model = find_model(expr, true);
+    Verdict::new(model.is_some(), model)
+}
+
+/// Checks whether every assignment makes `expr` true.
+///
+/// The model is a counter-model making `expr` false.
+pub fn is_valid(expr: &Expression) -> Verdict {
+    let model = find_model(expr, false);
+    Verdict::new(model.is_none(), model)
+}
+
+/// Checks whether every assignment makes `expr` false.
+///
+/// The model is a counter-model making `expr` true.
+pub fn is_contradiction(expr: &Expression) -> Verdict {
+    let model = find_model(expr, true);
+    Verdict::new(model.is_none(), model)
+}
+
+/// Checks whether `a` and `b` have the same value under every assignment.
+///
+/// The model is an assignment on which they differ.
+pub fn are_equivalent(a: &Expression, b: &Expression) -> Verdict {
+    // the expressions differ if one can be true while the other is false
+    let verdict = entails(std::slice::from_ref(a), b);
+    if !verdict.holds() {
+        return verdict;
+    }
+
+    entails(std::slice::from_ref(b), a)
+}
+
+/// Checks whether every assignment making all `premises` true also makes
+/// `conclusion` true.
+///
+/// The model is a counter-example making the premises true and the
+/// conclusion false.
+pub fn entails(premises: &[Expression], conclusion: &Expression) -> Verdict {
+    let tableau = TableauExpander::default().expand_entailment(premises, conclusion);
+    let model = Solutions::from(&tableau).iter().next().cloned();
+    Verdict::new(model.is_none(), model)
+}
+
+/// Checks an entailment like [`entails`], but with a [`SatSolver`] on the
+/// Tseitin encoding of the premises and the negated conclusion.
+///
+/// The model is a counter-example making the premises true and the
+/// conclusion false.
+pub fn sat_entails(premises: &[Expression], conclusion: &Expression) -> Verdict {
+    let mut cnf = Cnf::new();
+    for premise in premises {
+        cnf.assert(premise, true);
+    }
+    cnf.assert(conclusion, false);
+
+    let model = SatSolver::new(&cnf)
+        .solve()
+        .map(|model| Solution::from_model(&cnf, &model));
+    Verdict::new(model.is_none(), model)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Parser;
+
+    use super::*;
+
+    fn parse(source: &str) -> Expression {
+        Parser::new().parse(source).unwrap()
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(is_valid(&parse("a | ~a")).holds());
+        assert!(is_valid(&parse("(a -> b) & a -> b")).holds());
+        assert!(!is_valid(&parse("a | b")).holds());
+
+        let verdict = is_valid(&parse("a | b"));
+        let model = verdict.model().unwrap();
+        assert_eq!(model.get("a"), Some(false));
+        assert_eq!(model.get("b"), Some(false));
+
+        assert!(is_satisfiable(&parse("a & ~b")).holds());
+        assert_eq!(
+            is_satisfiable(&parse("a & ~b")).model().unwrap().get("b"),
+            Some(false)
+        );
+        assert!(!is_satisfiable(&parse("a & ~a")).holds());
+        assert!(is_satisfiable(&parse("a & ~a")).model().is_none());
+
+        assert!(is_contradiction(&parse("a & ~a")).holds());
+        assert!(is_contradiction(&parse("0")).holds());
+        assert!(!is_contradiction(&parse("a ^ b")).holds());
+
+        assert!(are_equivalent(&parse("a -> b"), &parse("~a | b")).holds());
+        assert!(are_equivalent(&parse("~(a & b)"), &parse("~a | ~b")).holds());
+
+        let verdict = are_equivalent(&parse("a -> b"), &parse("b -> a"));
+        assert!(!verdict.holds());
+
+        let model = verdict.model().unwrap();
+        assert_ne!(model.get("a"), model.get("b"));
+
+        let verdict = are_equivalent(&parse("a"), &parse("a & b"));
+        assert_eq!(verdict.model().unwrap().get("b"), Some(false));
+    }
+
+    #[test]
+    fn test_entails() {
+        assert!(entails(&[parse("p -> q"), parse("p")], &parse("q")).holds());
+        assert!(entails(&[parse("p & q")], &parse("q")).holds());
+        assert!(entails(&[], &parse("p | ~p")).holds());
+        assert!(entails(&[parse("p"), parse("~p")], &parse("q")).holds());
+
+        let verdict = entails(&[parse("p -> q"), parse("q")], &parse("p"));
+        assert!(!verdict.holds());
+
+        let model = verdict.model().unwrap();
+        assert_eq!(model.get("p"), Some(false));
+        assert_eq!(model.get("q"), Some(true));
+    }
+
+    #[test]
+    fn test_sat_entails() {
+        assert!(sat_entails(&[parse("p -> q"), parse("p")], &parse("q")).holds());
+        assert!(sat_entails(&[], &parse("p | ~p")).holds());
+        assert!(sat_entails(&[parse("p"), parse("~p")], &parse("q")).holds());
+
+        let verdict = sat_entails(&[parse("p -> q"), parse("q")], &parse("p"));
+        assert!(!verdict.holds());
+
+        let model = verdict.model().unwrap();
+        assert_eq!(model.get("p"), Some(false));
+        assert_eq!(model.get("q"), Some(true));
+
+        // variables that don't matter are left out
+        let verdict = sat_entails(&[parse("p | q")], &parse("p"));
+        assert_eq!(verdict.model().unwrap().get("p"), Some(false));
+    }
+}
@@ -0,0 +1,351 @@
+use std::collections::HashMap;
+
+use crate::{BinaryOperator, Expression, ExpressionKind, UnaryOperator};
+
+/// A formula in conjunctive normal form over numbered variables.
+///
+/// Clauses are lists of non-zero literals as in DIMACS, `n` is variable `n`
+/// and `-n` its negation. Variables created for atomic propositions keep
+/// their identifier, auxiliary variables have none.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cnf {
+    names: Vec<Option<String>>,
+    variables: HashMap<String, u32>,
+    clauses: Vec<Vec<i32>>,
+}
+
+impl Cnf {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a variable and returns its number, starting from 1.
+    pub fn add_variable(&mut self, name: Option<String>) -> u32 {
+        self.names.push(name.clone());
+        let variable = self.names.len() as u32;
+
+        if let Some(name) = name {
+            self.variables.insert(name, variable);
+        }
+
+        variable
+    }
+
+    /// Returns the variable of the atomic proposition `name`.
+    pub fn variable(&self, name: impl AsRef<str>) -> Option<u32> {
+        self.variables.get(name.as_ref()).copied()
+    }
+
+    /// Returns the identifier of `variable`, `None` for auxiliary variables.
+    pub fn name(&self, variable: u32) -> Option<&str> {
+        self.names
+            .get((variable as usize).checked_sub(1)?)?
+            .as_deref()
+    }
+
+    pub fn variable_count(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn add_clause(&mut self, clause: Vec<i32>) {
+        self.clauses.push(clause);
+    }
+
+    pub fn clauses(&self) -> &[Vec<i32>] {
+        &self.clauses
+    }
+}
+
+impl Cnf {
+    /// Adds the clauses requiring `expr` to evaluate to `expect`, using the
+    /// Tseitin transformation described in [`tseitin`].
+    ///
+    /// Only the clauses for the values a subexpression can need are added,
+    /// e.g. `x -> a & b` but not `a & b -> x` for a conjunction that has to
+    /// be true. The auxiliary variables of subexpressions that don't matter
+    /// can then be false, without assigning their atomic propositions.
+    /// Conjunctions that have to be true are asserted one operand at a time
+    /// and disjunctions that have to be true become a single clause, so they
+    /// don't need variables of their own.
+    ///
+    /// Atomic propositions share their variable with earlier expressions.
+    pub fn assert(&mut self, expr: &Expression, expect: bool) {
+        self.add_atoms(expr);
+        self.require(expr, expect);
+    }
+
+    /// Adds the clauses requiring `expr` to evaluate to `expect`, defining
+    /// auxiliary variables for both values.
+    fn add_expression(&mut self, expr: &Expression, expect: bool) {
+        self.add_atoms(expr);
+
+        let root = self.encode(expr, None);
+        self.add_clause(vec![if expect { root } else { -root }]);
+    }
+
+    /// Adds the clauses requiring `expr` to evaluate to `expect`, splitting
+    /// it into clauses as long as it is a conjunction of them.
+    fn require(&mut self, expr: &Expression, expect: bool) {
+        match expr.kind.as_ref() {
+            ExpressionKind::Paren(paren) => return self.require(&paren.expression, expect),
+            ExpressionKind::Unary(unary) => match unary.operator {
+                UnaryOperator::Negation(_) => return self.require(&unary.operand, !expect),
+            },
+            ExpressionKind::Binary(binary) => match (&binary.operator, expect) {
+                (BinaryOperator::Conjunction(_), true)
+                | (BinaryOperator::Disjunction(_), false) => {
+                    self.require(&binary.lhs, expect);
+                    return self.require(&binary.rhs, expect);
+                }
+                (BinaryOperator::Implication(_), false) => {
+                    self.require(&binary.lhs, true);
+                    return self.require(&binary.rhs, false);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        let mut clause = Vec::new();
+        self.add_literals(expr, expect, &mut clause);
+        self.add_clause(clause);
+    }
+
+    /// Pushes literals to `clause` whose disjunction holds exactly when
+    /// `expr` evaluates to `expect`.
+    fn add_literals(&mut self, expr: &Expression, expect: bool, clause: &mut Vec<i32>) {
+        match expr.kind.as_ref() {
+            ExpressionKind::Paren(paren) => {
+                return self.add_literals(&paren.expression, expect, clause);
+            }
+            ExpressionKind::Unary(unary) => match unary.operator {
+                UnaryOperator::Negation(_) => {
+                    return self.add_literals(&unary.operand, !expect, clause);
+                }
+            },
+            ExpressionKind::Binary(binary) => match (&binary.operator, expect) {
+                (BinaryOperator::Disjunction(_), true)
+                | (BinaryOperator::Conjunction(_), false) => {
+                    self.add_literals(&binary.lhs, expect, clause);
+                    return self.add_literals(&binary.rhs, expect, clause);
+                }
+                (BinaryOperator::Implication(_), true) => {
+                    self.add_literals(&binary.lhs, false, clause);
+                    return self.add_literals(&binary.rhs, true, clause);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        let literal = self.encode(expr, Some(expect));
+        clause.push(if expect { literal } else { -literal });
+    }
+
+    /// Adds a variable for every atomic proposition, in the order they
+    /// appear, so they are numbered before any auxiliary variable.
+    fn add_atoms(&mut self, expr: &Expression) {
+        match expr.kind.as_ref() {
+            ExpressionKind::TruthValue(_) => {}
+            ExpressionKind::Atomic(atomic) => {
+                if self.variable(&atomic.ident).is_none() {
+                    self.add_variable(Some(atomic.ident.clone()));
+                }
+            }
+            ExpressionKind::Paren(paren) => self.add_atoms(&paren.expression),
+            ExpressionKind::Unary(unary) => self.add_atoms(&unary.operand),
+            ExpressionKind::Binary(binary) => {
+                self.add_atoms(&binary.lhs);
+                self.add_atoms(&binary.rhs);
+            }
+        }
+    }
+
+    /// Returns a literal equivalent to `expr`, adding the clauses defining
+    /// any auxiliary variable it needs.
+    ///
+    /// With a `polarity`, the literal only implies `expr` if `true` and is
+    /// only implied by it if `false`.
+    fn encode(&mut self, expr: &Expression, polarity: Option<bool>) -> i32 {
+        match expr.kind.as_ref() {
+            ExpressionKind::TruthValue(truth_value) => {
+                let x = self.add_variable(None) as i32;
+                self.add_clause(vec![if truth_value.value { x } else { -x }]);
+                x
+            }
+            ExpressionKind::Atomic(atomic) => self.variable(&atomic.ident).unwrap() as i32,
+            ExpressionKind::Paren(paren) => self.encode(&paren.expression, polarity),
+            // negations don't need a variable of their own
+            ExpressionKind::Unary(unary) => match unary.operator {
+                UnaryOperator::Negation(_) => -self.encode(&unary.operand, polarity.map(|p| !p)),
+            },
+            ExpressionKind::Binary(binary) => {
+                let (lhs, rhs) = match binary.operator {
+                    BinaryOperator::Conjunction(_) | BinaryOperator::Disjunction(_) => {
+                        (polarity, polarity)
+                    }
+                    BinaryOperator::Implication(_) => (polarity.map(|p| !p), polarity),
+                    BinaryOperator::Equivalence(_) | BinaryOperator::ExclusiveDisjunction(_) => {
+                        (None, None)
+                    }
+                };
+
+                let a = self.encode(&binary.lhs, lhs);
+                let b = self.encode(&binary.rhs, rhs);
+                let x = self.add_variable(None) as i32;
+
+                let clauses = match binary.operator {
+                    BinaryOperator::Conjunction(_) => {
+                        vec![vec![-x, a], vec![-x, b], vec![x, -a, -b]]
+                    }
+                    BinaryOperator::Disjunction(_) => {
+                        vec![vec![-x, a, b], vec![x, -a], vec![x, -b]]
+                    }
+                    BinaryOperator::Implication(_) => {
+                        vec![vec![-x, -a, b], vec![x, a], vec![x, -b]]
+                    }
+                    BinaryOperator::Equivalence(_) => vec![
+                        vec![-x, -a, b],
+                        vec![-x, a, -b],
+                        vec![x, a, b],
+                        vec![x, -a, -b],
+                    ],
+                    BinaryOperator::ExclusiveDisjunction(_) => vec![
+                        vec![-x, a, b],
+                        vec![-x, -a, -b],
+                        vec![x, -a, b],
+                        vec![x, a, -b],
+                    ],
+                };
+
+                // the clauses with `-x` make `x` imply the expression
+                for clause in clauses {
+                    if polarity != Some(clause[0] > 0) {
+                        self.add_clause(clause);
+                    }
+                }
+
+                x
+            }
+        }
+    }
+}
+
+/// Converts `expr` to an equisatisfiable [`Cnf`] using the Tseitin
+/// transformation.
+///
+/// Every binary subexpression gets an auxiliary variable equivalent to it,
+/// so the result only grows linearly with `expr`, unlike
+/// [`to_cnf`](crate::to_cnf). The atomic propositions are numbered first,
+/// in the order they appear.
+///
+/// # Example
+/// ```rust
+/// # use dare::{tseitin, Parser};
+/// let expr = Parser::new().parse("a & ~b").unwrap();
+/// let cnf = tseitin(&expr);
+///
+/// assert_eq!(cnf.variable_count(), 3);
+/// assert_eq!(cnf.name(1), Some("a"));
+/// assert_eq!(cnf.name(2), Some("b"));
+/// assert_eq!(cnf.name(3), None);
+/// assert_eq!(
+///     cnf.clauses(),
+///     &[vec![-3, 1], vec![-3, -2], vec![3, -1, 2], vec![3]]
+/// );
+/// ```
+pub fn tseitin(expr: &Expression) -> Cnf {
+    let mut cnf = Cnf::new();
+    cnf.add_expression(expr, true);
+    cnf
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_util::Rng, Parser, TruthTable};
+
+    use super::*;
+
+    /// Counts the assignments satisfying every clause of `cnf`.
+    fn count_models(cnf: &Cnf) -> usize {
+        models(cnf).len()
+    }
+
+    /// Returns the assignments satisfying every clause of `cnf`, with the
+    /// value of variable `n` in bit `n - 1`.
+    fn models(cnf: &Cnf) -> Vec<u32> {
+        let count = cnf.variable_count();
+
+        (0..1u32 << count)
+            .filter(|assignment| {
+                cnf.clauses().iter().all(|clause| {
+                    clause.iter().any(|&literal| {
+                        let value = assignment >> (literal.unsigned_abs() - 1) & 1 == 1;
+                        value == (literal > 0)
+                    })
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_tseitin() {
+        let mut rng = Rng(0x7e17);
+
+        for _ in 0..200 {
+            let source = rng.source(3);
+            let expr = Parser::new().parse(&source).unwrap();
+            let cnf = tseitin(&expr);
+
+            // every auxiliary variable is determined by the atomic
+            // propositions, so both have the same number of models
+            let table = TruthTable::new(&expr).unwrap();
+            let models = table.rows().iter().filter(|row| row.result()).count();
+            assert_eq!(count_models(&cnf), models, "{}", source);
+
+            for variable in table.variables() {
+                let number = cnf.variable(variable).unwrap();
+                assert_eq!(cnf.name(number), Some(variable.as_str()));
+            }
+        }
+
+        for _ in 0..200 {
+            let source = rng.source(3);
+            let expr = Parser::new().parse(&source).unwrap();
+            let table = TruthTable::new(&expr).unwrap();
+
+            // the atomic propositions have a model exactly when the
+            // expression has the expected value
+            for expect in [true, false] {
+                let mut cnf = Cnf::new();
+                cnf.assert(&expr, expect);
+
+                let atoms = table.variables().len();
+                let mask = (1 << atoms) - 1;
+                let mut projected = models(&cnf)
+                    .into_iter()
+                    .map(|assignment| assignment & mask)
+                    .collect::<Vec<_>>();
+                projected.sort();
+                projected.dedup();
+
+                let expected = (0..1u32 << atoms)
+                    .filter(|&assignment| {
+                        table.rows().iter().any(|row| {
+                            row.result() == expect
+                                && (0..atoms)
+                                    .all(|i| row.assignment[i] == (assignment >> i & 1 == 1))
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(projected, expected, "{}", source);
+            }
+        }
+
+        let expr = Parser::new().parse("1 | 0").unwrap();
+        assert_eq!(count_models(&tseitin(&expr)), 1);
+
+        let expr = Parser::new().parse("a & ~a").unwrap();
+        assert_eq!(count_models(&tseitin(&expr)), 0);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{Error, TruthTable, TruthTableWriter};
+
+/// Writes a [`TruthTable`] as comma-separated values.
+///
+/// Fields are quoted as described in RFC 4180 when they contain a comma, a
+/// quote or a line break.
+///
+/// # Example
+/// ```rust
+/// # use dare::{CsvTruthTableWriter, Parser, TruthTable, TruthTableWriter};
+/// let expr = Parser::new().parse("a | b").unwrap();
+/// let table = TruthTable::new(&expr).unwrap();
+///
+/// let mut writer = CsvTruthTableWriter::default();
+/// writer.write_truth_table(&table).unwrap();
+///
+/// assert_eq!(writer.finalize(), "a,b,a | b\nT,T,T\nT,F,T\nF,T,T\nF,F,F\n");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CsvTruthTableWriter {
+    buffer: String,
+}
+
+impl CsvTruthTableWriter {
+    fn write_field(&mut self, field: &str) {
+        if field.contains([',', '"', '\n', '\r']) {
+            self.buffer += &format!("\"{}\"", field.replace('"', "\"\""));
+        } else {
+            self.buffer += field;
+        }
+    }
+
+    fn write_record<'a>(&mut self, fields: impl IntoIterator<Item = &'a str>) {
+        for (i, field) in fields.into_iter().enumerate() {
+            if i > 0 {
+                self.buffer += ",";
+            }
+
+            self.write_field(field);
+        }
+
+        self.buffer += "\n";
+    }
+
+    pub fn finalize(self) -> String {
+        self.buffer
+    }
+}
+
+impl TruthTableWriter for CsvTruthTableWriter {
+    type Error = Error;
+
+    fn write_truth_table(&mut self, table: &TruthTable) -> Result<(), Self::Error> {
+        let columns = table
+            .columns()
+            .iter()
+            .map(|column| column.to_string())
+            .collect::<Vec<_>>();
+
+        self.write_record(
+            table
+                .variables()
+                .iter()
+                .chain(columns.iter())
+                .map(String::as_str),
+        );
+
+        for row in table.rows() {
+            self.write_record(
+                row.assignment
+                    .iter()
+                    .chain(row.values.iter())
+                    .map(|&value| if value { "T" } else { "F" }),
+            );
+        }
+
+        Ok(())
+    }
+}
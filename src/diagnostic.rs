@@ -0,0 +1,265 @@
+use crate::{Error, Severity, Span};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const GREEN: &str = "\x1b[1;32m";
+const BLUE: &str = "\x1b[1;34m";
+
+/// Renders [`Error`]s as compiler-style reports with source snippets.
+///
+/// The first span of an [`Error`] is underlined with `^`, every following span
+/// is underlined with `-` and labelled with the message at the same index.
+/// Messages without a span are appended as notes, followed by the help text.
+///
+/// # Example
+/// ```rust
+/// # use dare::{DiagnosticWriter, Error, Span};
+/// let source = "a & b c";
+/// let error = Error::new()
+///     .with_msg("unexpected token after expression")
+///     .with_span(Span::new(6, 1));
+///
+/// let mut writer = DiagnosticWriter::new(source);
+/// writer.write_error(&error);
+///
+/// assert_eq!(
+///     writer.finalize(),
+///     "error: unexpected token after expression\n \
+///      --> 1:7\n  \
+///       |\n\
+///     1 | a & b c\n  \
+///       |       ^\n"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct DiagnosticWriter<'a> {
+    source: &'a str,
+    colored: bool,
+    buffer: String,
+}
+
+impl<'a> DiagnosticWriter<'a> {
+    /// Creates a new [`DiagnosticWriter`] for errors in `source`.
+    pub const fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            colored: false,
+            buffer: String::new(),
+        }
+    }
+
+    /// Enables ANSI colour codes in the output.
+    pub fn colored(&mut self) {
+        self.colored = true;
+    }
+
+    fn paint(&self, color: &str, text: &str) -> String {
+        if self.colored {
+            format!("{}{}{}", color, text, RESET)
+        } else {
+            String::from(text)
+        }
+    }
+
+    /// Returns the 0-based line index and the byte offset of the start of that line.
+    fn line_of(&self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.source.len());
+        let before = &self.source[..offset];
+
+        let line = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        (line, line_start)
+    }
+
+    fn line_text(&self, line_start: usize) -> &'a str {
+        let rest = &self.source[line_start..];
+        let line = rest.split('\n').next().unwrap_or_default();
+        line.strip_suffix('\r').unwrap_or(line)
+    }
+
+    /// Returns the 1-based line and column of `span`.
+    pub fn position(&self, span: Span) -> (usize, usize) {
+        let (line, line_start) = self.line_of(span.start());
+        let column = self.source[line_start..span.start().min(self.source.len())]
+            .chars()
+            .count();
+
+        (line + 1, column + 1)
+    }
+
+    pub fn write_error(&mut self, error: &Error) {
+        // consecutive reports are separated by an empty line
+        if !self.buffer.is_empty() {
+            self.buffer += "\n";
+        }
+
+        let headline = error
+            .messages()
+            .first()
+            .map_or("unknown error", String::as_str);
+
+        let color = match error.severity() {
+            Severity::Error => RED,
+            Severity::Warning => YELLOW,
+            Severity::Note => GREEN,
+        };
+
+        self.buffer += &format!(
+            "{}{}\n",
+            self.paint(color, &error.severity().to_string()),
+            self.paint(BOLD, &format!(": {}", headline)),
+        );
+
+        let spans = error.spans();
+        let last_line = spans
+            .iter()
+            .map(|span| self.position(*span).0)
+            .max()
+            .unwrap_or(1);
+        let gutter = " ".repeat(last_line.to_string().len());
+
+        if let Some(&primary) = spans.first() {
+            let (line, column) = self.position(primary);
+            self.buffer += &format!(
+                "{}{} {}:{}\n",
+                gutter,
+                self.paint(BLUE, "-->"),
+                line,
+                column
+            );
+            self.buffer += &format!("{} {}\n", gutter, self.paint(BLUE, "|"));
+        }
+
+        // spans are shown in source order, grouped by line
+        let mut order = (0..spans.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| (spans[i].start(), i));
+
+        let mut previous_line = None;
+        for i in order {
+            let span = spans[i];
+            let (line_index, line_start) = self.line_of(span.start());
+            let text = self.line_text(line_start);
+
+            if previous_line != Some(line_index) {
+                if previous_line.is_some_and(|previous| previous + 1 != line_index) {
+                    self.buffer += &format!("{}\n", self.paint(BLUE, "..."));
+                }
+
+                let number = format!("{:>width$}", line_index + 1, width = gutter.len());
+                self.buffer += &format!("{} {}", self.paint(BLUE, &number), self.paint(BLUE, "|"));
+
+                // tabs are shown as single spaces to keep the underlines aligned
+                if !text.is_empty() {
+                    self.buffer += " ";
+                    self.buffer += &text.replace('\t', " ");
+                }
+                self.buffer += "\n";
+
+                previous_line = Some(line_index);
+            }
+
+            let column = self.position(span).1 - 1;
+            let end = span.end().min(line_start + text.len()).max(span.start());
+            let length = self.source
+                [span.start().min(self.source.len())..end.min(self.source.len())]
+                .chars()
+                .count()
+                .max(1);
+
+            let (marker, color) = if i == 0 { ("^", color) } else { ("-", BLUE) };
+            let mut underline = self.paint(color, &marker.repeat(length));
+
+            if i > 0 {
+                if let Some(label) = error.messages().get(i) {
+                    underline += " ";
+                    underline += &self.paint(color, label);
+                }
+            }
+
+            self.buffer += &format!(
+                "{} {} {}{}\n",
+                gutter,
+                self.paint(BLUE, "|"),
+                " ".repeat(column),
+                underline
+            );
+        }
+
+        for note in error.messages().iter().skip(spans.len().max(1)) {
+            self.buffer += &format!("{} {} note: {}\n", gutter, self.paint(BLUE, "="), note);
+        }
+
+        if let Some(help) = error.help() {
+            self.buffer += &format!("{} {} help: {}\n", gutter, self.paint(BLUE, "="), help);
+        }
+    }
+
+    pub fn finalize(self) -> String {
+        self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostic_writer() {
+        let source = "p := q\nr\n\np := ~q";
+        let error = Error::new()
+            .with_msg("redefinition of 'p'")
+            .with_span(Span::new(10, 1))
+            .with_msg("previously defined here")
+            .with_span(Span::new(0, 1))
+            .with_msg("definitions cannot be changed");
+
+        let mut writer = DiagnosticWriter::new(source);
+        writer.write_error(&error);
+
+        let expected = [
+            "error: redefinition of 'p'",
+            " --> 4:1",
+            "  |",
+            "1 | p := q",
+            "  | - previously defined here",
+            "...",
+            "4 | p := ~q",
+            "  | ^",
+            "  = note: definitions cannot be changed",
+            "",
+        ];
+        assert_eq!(writer.finalize(), expected.join("\n"));
+
+        let mut writer = DiagnosticWriter::new("(a & b");
+        writer.write_error(
+            &Error::new()
+                .with_msg("unexpected end of file")
+                .with_span(Span::new(6, 0)),
+        );
+        assert!(writer.finalize().ends_with("1 | (a & b\n  |       ^\n"));
+
+        let mut writer = DiagnosticWriter::new("a -> b -> c");
+        writer.write_error(
+            &Error::new()
+                .with_severity(Severity::Warning)
+                .with_msg("ambiguous chain")
+                .with_span(Span::new(2, 2))
+                .with_help("add parentheses"),
+        );
+        assert_eq!(
+            writer.finalize(),
+            "warning: ambiguous chain\n --> 1:3\n  |\n1 | a -> b -> c\n  |   ^^\n  = help: add parentheses\n"
+        );
+
+        let mut writer = DiagnosticWriter::new("a & b");
+        writer.colored();
+        writer.write_error(&Error::new().with_msg("no spans"));
+        assert_eq!(
+            writer.finalize(),
+            format!("{RED}error{RESET}{BOLD}: no spans{RESET}\n")
+        );
+    }
+}
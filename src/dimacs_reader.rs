@@ -0,0 +1,532 @@
+use std::collections::HashMap;
+
+use crate::{
+    AtomicExpression, BinaryExpression, BinaryOperator, Cnf, Error, ErrorKind, Expression,
+    ExpressionKind, ParenExpression, Span, TruthValueExpression, UnaryExpression, UnaryOperator,
+};
+
+/// A clause as written in the source, every literal with its span.
+#[derive(Clone, Debug)]
+struct Clause {
+    literals: Vec<(i32, Span)>,
+    span: Span,
+}
+
+/// The problem read from a DIMACS file before it is turned into a [`Cnf`]
+/// or an [`Expression`].
+#[derive(Clone, Debug, Default)]
+struct Problem {
+    variables: u32,
+    names: HashMap<u32, String>,
+    clauses: Vec<Clause>,
+}
+
+impl Problem {
+    fn name(&self, variable: u32) -> String {
+        match self.names.get(&variable) {
+            Some(name) => name.clone(),
+            None => format!("x{}", variable),
+        }
+    }
+}
+
+/// Splits `line` into words, each with its span in the whole source.
+fn words(line: &str, offset: usize) -> Vec<(&str, Span)> {
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (i, c) in line
+        .char_indices()
+        .chain(std::iter::once((line.len(), ' ')))
+    {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some(i),
+            (Some(s), true) => {
+                words.push((&line[s..i], Span::new(offset + s, i - s)));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    words
+}
+
+fn is_identifier(word: &str) -> bool {
+    let mut chars = word.chars();
+
+    matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Reads problems in the DIMACS CNF format.
+///
+/// Variables are named `x1`, `x2`, … unless a comment of the form
+/// `c var <variable> <identifier>` names them, as written by
+/// [`DimacsWriter`](crate::DimacsWriter). Any other comment is ignored.
+///
+/// # Example
+/// ```rust
+/// # use dare::DimacsReader;
+/// let source = "c var 1 rain\np cnf 2 2\n1 -2 0\n2 0\n";
+/// let expr = DimacsReader::new().read(source).unwrap();
+///
+/// assert_eq!(expr.to_string(), "(rain | ~x2) & x2");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DimacsReader {
+    pub errors: Vec<Error>,
+}
+
+impl DimacsReader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads `source` as a [`Cnf`] keeping the numbering of the variables.
+    ///
+    /// Every error is pushed to [`Self::errors`] and the first of them is returned.
+    pub fn read_cnf(&mut self, source: impl AsRef<str>) -> Result<Cnf, Error> {
+        let problem = self.read_problem(source.as_ref())?;
+
+        let mut cnf = Cnf::new();
+        for variable in 1..=problem.variables {
+            cnf.add_variable(Some(problem.name(variable)));
+        }
+
+        for clause in &problem.clauses {
+            cnf.add_clause(
+                clause
+                    .literals
+                    .iter()
+                    .map(|&(literal, _)| literal)
+                    .collect(),
+            );
+        }
+
+        Ok(cnf)
+    }
+
+    /// Reads `source` as a conjunction of disjunctions of literals, spanning
+    /// the literals and clauses they were read from.
+    ///
+    /// Every error is pushed to [`Self::errors`] and the first of them is returned.
+    pub fn read(&mut self, source: impl AsRef<str>) -> Result<Expression, Error> {
+        let problem = self.read_problem(source.as_ref())?;
+
+        let literal = |(literal, span): (i32, Span)| {
+            let atomic_span = if literal < 0 {
+                Span::new(span.start() + 1, span.length() - 1)
+            } else {
+                span
+            };
+
+            let atomic = Expression {
+                kind: Box::new(ExpressionKind::Atomic(AtomicExpression {
+                    ident: problem.name(literal.unsigned_abs()),
+                    span: atomic_span,
+                })),
+                span: atomic_span,
+            };
+
+            if literal > 0 {
+                return atomic;
+            }
+
+            Expression {
+                kind: Box::new(ExpressionKind::Unary(UnaryExpression {
+                    operator: UnaryOperator::Negation("~"),
+                    operand: atomic,
+                    operator_span: Span::new(span.start(), 1),
+                })),
+                span,
+            }
+        };
+
+        let join = |lhs: Expression, operator, rhs: Expression, operator_span| Expression {
+            span: lhs.span + rhs.span,
+            kind: Box::new(ExpressionKind::Binary(BinaryExpression {
+                lhs,
+                operator,
+                rhs,
+                operator_span,
+            })),
+        };
+
+        let truth_value = |value, span| Expression {
+            kind: Box::new(ExpressionKind::TruthValue(TruthValueExpression {
+                value,
+                span,
+            })),
+            span,
+        };
+
+        let mut expression: Option<Expression> = None;
+
+        for clause in &problem.clauses {
+            // an empty clause can't be satisfied
+            let mut disjunction = clause
+                .literals
+                .iter()
+                .map(|&literal_span| literal(literal_span))
+                .reduce(|lhs, rhs| join(lhs, BinaryOperator::Disjunction("|"), rhs, clause.span))
+                .unwrap_or_else(|| truth_value(false, clause.span));
+
+            if clause.literals.len() > 1 {
+                disjunction = Expression {
+                    span: clause.span,
+                    kind: Box::new(ExpressionKind::Paren(ParenExpression {
+                        expression: disjunction,
+                    })),
+                };
+            }
+
+            expression = Some(match expression {
+                Some(lhs) => join(
+                    lhs,
+                    BinaryOperator::Conjunction("&"),
+                    disjunction,
+                    clause.span,
+                ),
+                None => disjunction,
+            });
+        }
+
+        // a problem without clauses is satisfied by every assignment
+        Ok(expression.unwrap_or_else(|| truth_value(true, Span::new(0, 0))))
+    }
+
+    fn read_problem(&mut self, source: &str) -> Result<Problem, Error> {
+        let error_count = self.errors.len();
+
+        let mut problem = Problem::default();
+        let mut header: Option<(Span, Option<usize>)> = None;
+        let mut names: Vec<(u32, String, Span)> = Vec::new();
+        let mut clause: Vec<(i32, Span)> = Vec::new();
+        let mut offset = 0;
+
+        for line in source.split_inclusive('\n') {
+            let line_offset = offset;
+            offset += line.len();
+
+            let words = words(line, line_offset);
+
+            match words.first() {
+                None => continue,
+                Some(("c", _)) => {
+                    if let [_, ("var", span), (variable, _), (name, name_span)] = words[..] {
+                        if let (Ok(variable), true) = (variable.parse(), is_identifier(name)) {
+                            names.push((variable, name.to_string(), span + name_span));
+                        }
+                    }
+
+                    continue;
+                }
+                Some(("p", span)) => {
+                    if let Some((previous, _)) = header {
+                        let error = Error::new()
+                            .with_kind(ErrorKind::UnexpectedToken)
+                            .with_msg("the problem line is repeated")
+                            .with_span(*span)
+                            .with_msg("the first problem line is here")
+                            .with_span(previous);
+                        self.errors.push(error);
+                        continue;
+                    }
+
+                    let span = words.iter().fold(*span, |span, &(_, word)| span + word);
+                    let counts = self.read_header(&words, span);
+
+                    problem.variables = counts.map_or(0, |(variables, _)| variables);
+                    header = Some((span, counts.map(|(_, clauses)| clauses)));
+
+                    continue;
+                }
+                // SATLIB instances end with a line holding `%`
+                Some(("%", _)) => break,
+                Some(_) => {}
+            }
+
+            for (word, span) in words {
+                if header.is_none() {
+                    let error = Error::new()
+                        .with_kind(ErrorKind::ExpectedToken)
+                        .with_msg("expected a `p cnf <variables> <clauses>` line")
+                        .with_span(span)
+                        .with_help("clauses must follow the problem line");
+                    self.errors.push(error);
+                    return Err(self.errors[error_count].clone());
+                }
+
+                let literal = match word.parse::<i32>() {
+                    Ok(literal) => literal,
+                    Err(_) => {
+                        let error = Error::new()
+                            .with_kind(ErrorKind::UnexpectedToken)
+                            .with_msg("expected a literal")
+                            .with_span(span)
+                            .with_help("literals are non-zero integers and clauses end with 0");
+                        self.errors.push(error);
+                        continue;
+                    }
+                };
+
+                if literal == 0 {
+                    let span = clause.first().map_or(span, |&(_, start)| start + span);
+
+                    problem.clauses.push(Clause {
+                        literals: std::mem::take(&mut clause),
+                        span,
+                    });
+                    continue;
+                }
+
+                // a malformed problem line has already been reported
+                let declared = matches!(header, Some((_, Some(_))));
+
+                if declared && literal.unsigned_abs() > problem.variables {
+                    let error = Error::new()
+                        .with_kind(ErrorKind::VariableOutOfRange)
+                        .with_msg(format!(
+                            "variable {} is out of range",
+                            literal.unsigned_abs()
+                        ))
+                        .with_span(span)
+                        .with_msg(format!(
+                            "the problem only declares {} variables",
+                            problem.variables
+                        ))
+                        .with_span(header.unwrap().0);
+                    self.errors.push(error);
+                }
+
+                clause.push((literal, span));
+            }
+        }
+
+        if let Some(&(_, span)) = clause.last() {
+            let error = Error::new()
+                .with_kind(ErrorKind::UnexpectedEndOfFile)
+                .with_msg("the last clause isn't terminated")
+                .with_span(span)
+                .with_help("end the clause with 0");
+            self.errors.push(error);
+
+            // still count it, so the clause count isn't reported as well
+            problem.clauses.push(Clause {
+                literals: clause,
+                span,
+            });
+        }
+
+        match header {
+            None => {
+                let error = Error::new()
+                    .with_kind(ErrorKind::UnexpectedEndOfFile)
+                    .with_msg("expected a `p cnf <variables> <clauses>` line")
+                    .with_span(Span::new(source.len(), 0));
+                self.errors.push(error);
+            }
+            Some((span, Some(count))) if count != problem.clauses.len() => {
+                let error = Error::new()
+                    .with_kind(ErrorKind::CountMismatch)
+                    .with_msg(format!(
+                        "expected {} clauses but found {}",
+                        count,
+                        problem.clauses.len()
+                    ))
+                    .with_span(span);
+                self.errors.push(error);
+            }
+            Some(_) => {}
+        }
+
+        let mut spans = HashMap::new();
+        let mut variables = HashMap::new();
+        for (variable, name, span) in names {
+            if variable == 0 || variable > problem.variables {
+                let error = Error::new()
+                    .with_kind(ErrorKind::VariableOutOfRange)
+                    .with_msg(format!("variable {} is out of range", variable))
+                    .with_span(span);
+                self.errors.push(error);
+                continue;
+            }
+
+            if let Some(&other) = variables.get(&name) {
+                let error = Error::new()
+                    .with_kind(ErrorKind::Redefinition)
+                    .with_msg(format!("`{}` already names variable {}", name, other))
+                    .with_span(span);
+                self.errors.push(error);
+                continue;
+            }
+
+            variables.insert(name.clone(), variable);
+            problem.names.insert(variable, name);
+            spans.insert(variable, span);
+        }
+
+        // unnamed variables fall back to `x<variable>`, which must stay unique
+        for (variable, name) in (1..=problem.variables)
+            .filter_map(|variable| Some((variable, problem.names.get(&variable)?)))
+        {
+            let other = name
+                .strip_prefix('x')
+                .and_then(|number| number.parse::<u32>().ok())
+                .filter(|&other| {
+                    other != variable
+                        && (1..=problem.variables).contains(&other)
+                        && !problem.names.contains_key(&other)
+                        && problem.name(other) == *name
+                });
+
+            if let Some(other) = other {
+                let error = Error::new()
+                    .with_kind(ErrorKind::Redefinition)
+                    .with_msg(format!(
+                        "`{}` is already the name of unnamed variable {}",
+                        name, other
+                    ))
+                    .with_span(spans[&variable])
+                    .with_help(format!(
+                        "name variable {} as well or choose another name",
+                        other
+                    ));
+                self.errors.push(error);
+            }
+        }
+
+        match self.errors.get(error_count) {
+            Some(error) => Err(error.clone()),
+            None => Ok(problem),
+        }
+    }
+
+    /// Checks that `words` are `p cnf <variables> <clauses>` and returns both
+    /// numbers, pushing an error otherwise.
+    fn read_header(&mut self, words: &[(&str, Span)], span: Span) -> Option<(u32, usize)> {
+        let error = |message: &str, span| {
+            Error::new()
+                .with_kind(ErrorKind::ExpectedToken)
+                .with_msg(message)
+                .with_span(span)
+                .with_help("the problem line is `p cnf <variables> <clauses>`")
+        };
+
+        let error = match words {
+            [_, ("cnf", _), (variables, variables_span), (clauses, clauses_span)] => {
+                match (variables.parse(), clauses.parse()) {
+                    (Ok(variables), Ok(clauses)) => return Some((variables, clauses)),
+                    (Err(_), _) => error("expected the number of variables", *variables_span),
+                    (_, Err(_)) => error("expected the number of clauses", *clauses_span),
+                }
+            }
+            [_, ("cnf", _), _, _, (_, extra_span), ..] => {
+                error("unexpected word after the problem line", *extra_span)
+            }
+            [_, ("cnf", _), ..] => error("incomplete problem line", span),
+            [_, (_, format_span), ..] => error("expected `cnf`", *format_span),
+            _ => error("incomplete problem line", span),
+        };
+
+        self.errors.push(error);
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tseitin, DimacsWriter, Parser, TruthTable};
+
+    use super::*;
+
+    fn read_error(source: &str) -> Error {
+        let mut reader = DimacsReader::new();
+        let error = reader.read(source).unwrap_err();
+        assert_eq!(reader.errors.len(), 1, "{:?}", reader.errors);
+        error
+    }
+
+    #[test]
+    fn test_dimacs_reader() {
+        let source = "c a comment\nc 3 variables\nc var 2 b\np cnf 3 3\n1 -2\n 3 0 -1 0\n0\n%\n0\n";
+        let expr = DimacsReader::new().read(source).unwrap();
+        assert_eq!(expr.to_string(), "(x1 | ~b | x3) & ~x1 & 0");
+
+        // spans point back into the source
+        let clause = &source[expr.span.range()];
+        assert_eq!(clause, "1 -2\n 3 0 -1 0\n0");
+
+        let expr = DimacsReader::new().read("p cnf 0 0\n").unwrap();
+        assert_eq!(expr.to_string(), "1");
+
+        // the writer and the reader agree on the numbering and names
+        let expr = Parser::new().parse("(a -> b) ^ c").unwrap();
+        let cnf = tseitin(&expr);
+        let mut writer = DimacsWriter::default();
+        writer.write_cnf(&cnf);
+
+        let read = DimacsReader::new().read_cnf(writer.finalize()).unwrap();
+        assert_eq!(read.clauses(), cnf.clauses());
+        assert_eq!(read.variable("c"), cnf.variable("c"));
+        assert_eq!(read.name(4), Some("x4"));
+
+        let table = TruthTable::new(
+            &DimacsReader::new()
+                .read("p cnf 2 2\n1 2 0\n-1 -2 0\n")
+                .unwrap(),
+        )
+        .unwrap();
+        let results = table
+            .rows()
+            .iter()
+            .map(|row| row.result())
+            .collect::<Vec<_>>();
+        assert_eq!(results, [false, true, true, false]);
+
+        let error = read_error("1 2 0\n");
+        assert_eq!(error.kind(), ErrorKind::ExpectedToken);
+        assert_eq!(error.spans()[0], Span::new(0, 1));
+
+        let error = read_error("p dnf 2 1\n1 0\n");
+        assert_eq!(error.kind(), ErrorKind::ExpectedToken);
+        assert_eq!(error.spans()[0], Span::new(2, 3));
+
+        let error = read_error("p cnf two 1\n");
+        assert_eq!(error.spans()[0], Span::new(6, 3));
+
+        let error = read_error("p cnf 2 1\n1 a 0\n");
+        assert_eq!(error.kind(), ErrorKind::UnexpectedToken);
+        assert_eq!(error.spans()[0], Span::new(12, 1));
+
+        let error = read_error("p cnf 2 1\n1 3 0\n");
+        assert_eq!(error.kind(), ErrorKind::VariableOutOfRange);
+        assert_eq!(error.spans(), [Span::new(12, 1), Span::new(0, 9)]);
+
+        let error = read_error("p cnf 2 2\n1 2 0\n");
+        assert_eq!(error.kind(), ErrorKind::CountMismatch);
+        assert_eq!(error.messages()[0], "expected 2 clauses but found 1");
+
+        let error = read_error("p cnf 2 1\n1 2\n");
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEndOfFile);
+
+        let error = read_error("c var 3 a\np cnf 2 0\n");
+        assert_eq!(error.kind(), ErrorKind::VariableOutOfRange);
+        assert_eq!(error.spans()[0], Span::new(2, 7));
+
+        let error = read_error("c var 1 a\nc var 2 a\np cnf 2 0\n");
+        assert_eq!(error.kind(), ErrorKind::Redefinition);
+        assert_eq!(error.spans()[0], Span::new(12, 7));
+
+        // a name can't take the default name of another variable
+        let error = read_error("c var 1 x2\np cnf 2 1\n1 -2 0\n");
+        assert_eq!(error.kind(), ErrorKind::Redefinition);
+        assert_eq!(error.spans()[0], Span::new(2, 8));
+
+        let expr = DimacsReader::new()
+            .read("c var 1 x2\nc var 2 x1\np cnf 2 1\n1 -2 0\n")
+            .unwrap();
+        assert_eq!(expr.to_string(), "(x2 | ~x1)");
+    }
+}
@@ -0,0 +1,55 @@
+use crate::Cnf;
+
+/// Writes a [`Cnf`] in the DIMACS CNF format read by most SAT solvers.
+///
+/// The identifier of every named variable is listed in a `c var <variable>
+/// <identifier>` comment before the problem line.
+///
+/// # Example
+/// ```rust
+/// # use dare::{tseitin, DimacsWriter, Parser};
+/// let expr = Parser::new().parse("a | ~b").unwrap();
+///
+/// let mut writer = DimacsWriter::default();
+/// writer.write_cnf(&tseitin(&expr));
+///
+/// let expected = [
+///     "c var 1 a",
+///     "c var 2 b",
+///     "p cnf 3 4",
+///     "-3 1 -2 0",
+///     "3 -1 0",
+///     "3 2 0",
+///     "3 0",
+///     "",
+/// ];
+/// assert_eq!(writer.finalize(), expected.join("\n"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DimacsWriter {
+    buffer: String,
+}
+
+impl DimacsWriter {
+    pub fn write_cnf(&mut self, cnf: &Cnf) {
+        for variable in 1..=cnf.variable_count() as u32 {
+            if let Some(name) = cnf.name(variable) {
+                self.buffer += &format!("c var {} {}\n", variable, name);
+            }
+        }
+
+        self.buffer += &format!("p cnf {} {}\n", cnf.variable_count(), cnf.clauses().len());
+
+        for clause in cnf.clauses() {
+            for literal in clause {
+                self.buffer += &format!("{} ", literal);
+            }
+
+            self.buffer += "0\n";
+        }
+    }
+
+    pub fn finalize(self) -> String {
+        self.buffer
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    Error, Expression, ExpressionKind, ExpressionWriter, SharedIdMap, Tableau, TableauWriter,
+};
+
+/// Escapes `text` for use inside a quoted DOT string.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Writes an [`Expression`] as a Graphviz `digraph` with one node per
+/// [`ExpressionKind`].
+///
+/// # Example
+/// ```rust
+/// # use dare::{DotExpressionWriter, ExpressionWriter, Parser};
+/// let expr = Parser::new().parse("a & ~b").unwrap();
+///
+/// let mut writer = DotExpressionWriter::default();
+/// writer.write_expression(&expr).unwrap();
+///
+/// assert_eq!(
+///     writer.finalize(),
+///     "digraph {\n    \
+///         node0 [label=\"&\"];\n    \
+///         node1 [label=\"a\"];\n    \
+///         node0 -> node1;\n    \
+///         node2 [label=\"~\"];\n    \
+///         node3 [label=\"b\"];\n    \
+///         node2 -> node3;\n    \
+///         node0 -> node2;\n\
+///     }\n"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DotExpressionWriter {
+    nodes: usize,
+    buffer: String,
+}
+
+impl DotExpressionWriter {
+    /// Writes `expr` and returns the name of its node.
+    fn write_node(&mut self, expr: &Expression) -> String {
+        let name = format!("node{}", self.nodes);
+        self.nodes += 1;
+
+        let label = match expr.kind.as_ref() {
+            ExpressionKind::TruthValue(truth_value) => truth_value.to_string(),
+            ExpressionKind::Atomic(atomic) => atomic.to_string(),
+            ExpressionKind::Paren(_) => String::from("( )"),
+            ExpressionKind::Unary(unary) => unary.operator.to_string(),
+            ExpressionKind::Binary(binary) => binary.operator.to_string(),
+        };
+
+        self.buffer += &format!("    {} [label=\"{}\"];\n", name, escape(&label));
+
+        let children = match expr.kind.as_ref() {
+            ExpressionKind::TruthValue(_) | ExpressionKind::Atomic(_) => Vec::new(),
+            ExpressionKind::Paren(paren) => vec![&paren.expression],
+            ExpressionKind::Unary(unary) => vec![&unary.operand],
+            ExpressionKind::Binary(binary) => vec![&binary.lhs, &binary.rhs],
+        };
+
+        for child in children {
+            let child = self.write_node(child);
+            self.buffer += &format!("    {} -> {};\n", name, child);
+        }
+
+        name
+    }
+
+    pub fn finalize(self) -> String {
+        format!("digraph {{\n{}}}\n", self.buffer)
+    }
+}
+
+impl ExpressionWriter for DotExpressionWriter {
+    type Error = Error;
+
+    fn write_expression(&mut self, expr: &Expression) -> Result<(), Self::Error> {
+        self.write_node(expr);
+
+        Ok(())
+    }
+}
+
+/// Writes a [`Tableau`] as a Graphviz `digraph`.
+///
+/// Every [`Expectation`](crate::Expectation) becomes a node labelled
+/// `expr : T/F (n)`, the expectations of a branch are chained by plain edges
+/// and every [`TableauBranch`](crate::TableauBranch) becomes an edge labelled
+/// with the number of the expectation it expands. Closed branches end in a
+/// `×` node.
+///
+/// # Example
+/// ```rust
+/// # use dare::{DotTableauWriter, Parser, TableauBuilder, TableauWriter};
+/// let expr = Parser::new().parse("~a").unwrap();
+/// let tableau = TableauBuilder::default().build_expression(&expr, true);
+///
+/// let mut writer = DotTableauWriter::default();
+/// writer.write_tableau(&tableau).unwrap();
+///
+/// assert_eq!(
+///     writer.finalize(),
+///     "digraph {\n    \
+///         node [shape=plaintext];\n    \
+///         node0 [label=\"~a : T (1)\"];\n    \
+///         node1 [label=\"a : F (2)\"];\n    \
+///         node0 -> node1 [label=\"1\"];\n\
+///     }\n"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DotTableauWriter {
+    nodes: usize,
+    id_map: SharedIdMap,
+    buffer: String,
+}
+
+impl DotTableauWriter {
+    pub fn share_ids(&mut self, id_map: &SharedIdMap) {
+        self.id_map = id_map.clone();
+    }
+
+    fn next_node(&mut self, label: &str) -> String {
+        let name = format!("node{}", self.nodes);
+        self.nodes += 1;
+
+        self.buffer += &format!("    {} [label=\"{}\"];\n", name, escape(label));
+
+        name
+    }
+
+    /// Writes `tableau` below `parent`, the edge from `parent` is labelled
+    /// with the id of the expanded expectation if it starts a branch.
+    fn write_node(&mut self, tableau: &Tableau, mut parent: Option<(String, Option<u32>)>) {
+        for expectation in &tableau.expectations {
+            let value = if expectation.truth_value { "T" } else { "F" };
+            let label = format!(
+                "{} : {} ({})",
+                expectation.expr,
+                value,
+                self.id_map.get_id(expectation.id)
+            );
+            let name = self.next_node(&label);
+
+            if let Some((parent, id)) = parent {
+                self.write_edge(&parent, &name, id);
+            }
+
+            parent = Some((name, None));
+        }
+
+        if tableau.is_closed() {
+            if let Some((last, _)) = &parent {
+                let name = self.next_node("×");
+                self.write_edge(last, &name, None);
+            }
+        }
+
+        for branch in &tableau.branches {
+            let parent = parent
+                .as_ref()
+                .map(|(name, id)| (name.clone(), id.or(Some(branch.expectation))));
+
+            self.write_node(branch, parent);
+        }
+    }
+
+    fn write_edge(&mut self, parent: &str, name: &str, id: Option<u32>) {
+        match id {
+            Some(id) => {
+                let id = self.id_map.get_id(id);
+                self.buffer += &format!("    {} -> {} [label=\"{}\"];\n", parent, name, id);
+            }
+            None => self.buffer += &format!("    {} -> {};\n", parent, name),
+        }
+    }
+
+    pub fn finalize(self) -> String {
+        format!(
+            "digraph {{\n    node [shape=plaintext];\n{}}}\n",
+            self.buffer
+        )
+    }
+}
+
+impl TableauWriter for DotTableauWriter {
+    type Error = Error;
+
+    fn write_tableau(&mut self, tableau: &Tableau) -> Result<(), Self::Error> {
+        self.write_node(tableau, None);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Parser, TableauBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_dot_tableau() {
+        let expr = Parser::new().parse("(a | b) & ~a").unwrap();
+        let tableau = TableauBuilder::default().build_expression(&expr, true);
+
+        let mut writer = DotTableauWriter::default();
+        writer.write_tableau(&tableau).unwrap();
+
+        let expected = [
+            "digraph {",
+            "    node [shape=plaintext];",
+            "    node0 [label=\"(a | b) & ~a : T (1)\"];",
+            "    node1 [label=\"a | b : T (2)\"];",
+            "    node0 -> node1 [label=\"1\"];",
+            "    node2 [label=\"~a : T (3)\"];",
+            "    node1 -> node2;",
+            "    node3 [label=\"a : T (4)\"];",
+            "    node2 -> node3 [label=\"2\"];",
+            "    node4 [label=\"a : F (5)\"];",
+            "    node3 -> node4 [label=\"3\"];",
+            "    node5 [label=\"×\"];",
+            "    node4 -> node5;",
+            "    node6 [label=\"b : T (6)\"];",
+            "    node2 -> node6 [label=\"2\"];",
+            "    node7 [label=\"a : F (7)\"];",
+            "    node6 -> node7 [label=\"3\"];",
+            "}",
+            "",
+        ];
+        assert_eq!(writer.finalize(), expected.join("\n"));
+    }
+}
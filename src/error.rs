@@ -1,10 +1,65 @@
 use crate::Span;
 
+/// The category of an [`Error`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The lexer found a symbol that isn't part of the language.
+    UnexpectedSymbol,
+    /// The lexer expected a specific symbol, e.g. the `>` in `<->`.
+    ExpectedSymbol,
+    /// The source ended in the middle of a token or statement.
+    UnexpectedEndOfFile,
+    /// The parser expected an expression.
+    ExpectedExpression,
+    /// The parser expected a specific token, e.g. a closing parenthesis.
+    ExpectedToken,
+    /// The parser found a token where none was expected.
+    UnexpectedToken,
+    /// Non-associative operators were chained without parentheses.
+    NonAssociative,
+    /// An identifier has neither a value nor a definition.
+    UnknownIdentifier,
+    /// A name was defined more than once.
+    Redefinition,
+    /// A definition refers to itself, directly or through other definitions.
+    CyclicDefinition,
+    /// A statement can't be turned into a tableau.
+    UnsupportedStatement,
+    /// The number of items found differs from the declared number, e.g. the
+    /// clauses of a DIMACS problem.
+    CountMismatch,
+    /// A number refers to a variable the problem doesn't declare, e.g. a
+    /// literal of a DIMACS clause.
+    VariableOutOfRange,
+    /// Any other error.
+    #[default]
+    Other,
+}
+
+/// How severe an [`Error`] is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Note,
+    Warning,
+    #[default]
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Note => f.write_str("note"),
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
 /// A spanned error containing multiple messages and spans.
 ///
 /// # Example
 /// ```rust
-/// # use dare::{Error, Span};
+/// # use dare::{Error, ErrorKind, Severity, Span};
 /// // create an error
 /// let err = Error::new()
 ///     .with_msg("This is an example message")
@@ -12,24 +67,51 @@ use crate::Span;
 ///
 /// assert_eq!(err.messages()[0], "This is an example message");
 /// assert_eq!(err.spans()[0], Span::new(2, 5));
+/// assert_eq!(err.kind(), ErrorKind::Other);
+/// assert_eq!(err.severity(), Severity::Error);
+/// assert_eq!(err.to_string(), "This is an example message");
 /// ```
 #[derive(Clone, Debug)]
 pub struct Error {
+    kind: ErrorKind,
+    severity: Severity,
     messages: Vec<String>,
     spans: Vec<Span>,
+    help: Option<String>,
+}
+
+impl Default for Error {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Error {
-    /// Creates a new empty [`Error`].
+    /// Creates a new empty [`Error`] of kind [`ErrorKind::Other`].
     ///
     /// See [`Self::with_msg`] and [`Self::with_span`].
     pub const fn new() -> Self {
         Self {
+            kind: ErrorKind::Other,
+            severity: Severity::Error,
             messages: Vec::new(),
             spans: Vec::new(),
+            help: None,
         }
     }
 
+    /// Sets the [`ErrorKind`] of `self`.
+    pub fn with_kind(mut self, kind: ErrorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the [`Severity`] of `self`.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
     /// Adds a message to `self`.
     pub fn with_msg(mut self, message: impl Into<String>) -> Self {
         self.messages.push(message.into());
@@ -42,6 +124,22 @@ impl Error {
         self
     }
 
+    /// Sets a help text describing how to fix `self`.
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Returns the [`ErrorKind`] of `self`.
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the [`Severity`] of `self`.
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+
     /// Returns all messages in `self`.
     pub fn messages(&self) -> &[String] {
         &self.messages
@@ -51,4 +149,21 @@ impl Error {
     pub fn spans(&self) -> &[Span] {
         &self.spans
     }
+
+    /// Returns the help text of `self`.
+    pub fn help(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
 }
+
+/// Displays the first message, the remaining messages label the spans.
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.messages.first() {
+            Some(message) => f.write_str(message),
+            None => write!(f, "{:?}", self.kind),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
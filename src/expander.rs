@@ -0,0 +1,315 @@
+use std::rc::Rc;
+
+use crate::{BinaryOperator, Expectation, Expression, ExpressionKind, Tableau, TableauBranch};
+
+/// A persistent stack, pushing onto a clone shares every existing element.
+#[derive(Debug)]
+struct Stack<T> {
+    head: Option<Rc<(T, Stack<T>)>>,
+}
+
+impl<T> Clone for Stack<T> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+        }
+    }
+}
+
+impl<T: Copy> Stack<T> {
+    const fn new() -> Self {
+        Self { head: None }
+    }
+
+    fn push(&mut self, value: T) {
+        let tail = Self {
+            head: self.head.take(),
+        };
+        self.head = Some(Rc::new((value, tail)));
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let head = self.head.take()?;
+        let (value, tail) = &*head;
+        self.head = tail.head.clone();
+        Some(*value)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        std::iter::successors(self.head.as_deref(), |(_, tail)| tail.head.as_deref())
+            .map(|(value, _)| *value)
+    }
+}
+
+/// The state of a single branch, cloning it is cheap as every clone shares
+/// the formulas of the branch it was split from.
+#[derive(Clone, Debug)]
+struct Branch<'a> {
+    /// Formulas that haven't been added to the branch yet.
+    pending: Stack<(&'a Expression, bool)>,
+    /// Branching formulas waiting for every non-branching formula to expand.
+    branching: Stack<(&'a Expression, bool, u32)>,
+    literals: Stack<(&'a str, bool, u32)>,
+}
+
+/// Builds a [`Tableau`] by expanding one branch at a time.
+///
+/// Unlike [`TableauBuilder`](crate::TableauBuilder), non-branching (α) rules
+/// are applied before branching (β) rules, branches stop expanding as soon as
+/// they close and split branches share the formulas above the split instead
+/// of copying them into every leaf.
+///
+/// # Example
+/// ```rust
+/// # use dare::{Parser, TableauExpander};
+/// let expr = Parser::new().parse("(a | b) & (c | d) & ~a & a").unwrap();
+/// let tableau = TableauExpander::default().expand(&expr, true);
+///
+/// // the conflict is found before either disjunction is split
+/// assert!(tableau.is_closed());
+/// assert!(tableau.branches.is_empty());
+/// ```
+#[derive(Default)]
+pub struct TableauExpander {
+    id: u32,
+}
+
+impl TableauExpander {
+    pub fn next_id(&mut self) -> u32 {
+        let id = self.id;
+        self.id += 1;
+        id
+    }
+
+    /// Builds a tableau expecting `expr` to evaluate to `expect`.
+    #[must_use]
+    pub fn expand(&mut self, expr: &Expression, expect: bool) -> Tableau {
+        self.expand_all(&[], expr, expect)
+    }
+
+    /// Builds a tableau expecting every premise to be true and the conclusion
+    /// to be false.
+    #[must_use]
+    pub fn expand_entailment(
+        &mut self,
+        premises: &[Expression],
+        conclusion: &Expression,
+    ) -> Tableau {
+        self.expand_all(premises, conclusion, false)
+    }
+
+    fn expand_all(
+        &mut self,
+        premises: &[Expression],
+        conclusion: &Expression,
+        expect: bool,
+    ) -> Tableau {
+        let mut branch = Branch {
+            pending: Stack::new(),
+            branching: Stack::new(),
+            literals: Stack::new(),
+        };
+
+        // pending formulas are popped in reverse order
+        branch.pending.push((conclusion, expect));
+        for premise in premises.iter().rev() {
+            branch.pending.push((premise, true));
+        }
+
+        self.expand_branch(branch)
+    }
+
+    fn expand_branch(&mut self, mut branch: Branch<'_>) -> Tableau {
+        let mut tableau = Tableau {
+            expectations: Vec::new(),
+            branches: Vec::new(),
+            closed: None,
+        };
+
+        while let Some((expr, expect)) = branch.pending.pop() {
+            self.add(&mut tableau, &mut branch, expr, expect);
+
+            if tableau.is_closed() {
+                return tableau;
+            }
+        }
+
+        if let Some((expr, expect, id)) = branch.branching.pop() {
+            for alternative in Self::alternatives(expr, expect) {
+                let mut child = branch.clone();
+
+                for &(expr, expect) in alternative.iter().rev() {
+                    child.pending.push((expr, expect));
+                }
+
+                let child = self.expand_branch(child);
+                tableau.branches.push(TableauBranch::new(child, id));
+            }
+        }
+
+        tableau
+    }
+
+    /// Adds `expr` to the branch, applying its rule if it doesn't branch.
+    fn add<'a>(
+        &mut self,
+        tableau: &mut Tableau,
+        branch: &mut Branch<'a>,
+        expr: &'a Expression,
+        expect: bool,
+    ) {
+        // parentheses don't get a row of their own
+        if let ExpressionKind::Paren(paren) = expr.kind.as_ref() {
+            branch.pending.push((&paren.expression, expect));
+            return;
+        }
+
+        let id = self.next_id();
+        tableau
+            .expectations
+            .push(Expectation::new(expr.clone(), expect, id));
+
+        match expr.kind.as_ref() {
+            ExpressionKind::TruthValue(truth_value) => {
+                if truth_value.value != expect {
+                    tableau.closed = Some((id, id));
+                }
+            }
+            ExpressionKind::Atomic(atomic) => {
+                let conflict = branch
+                    .literals
+                    .iter()
+                    .find(|&(ident, value, _)| ident == atomic.ident && value != expect);
+
+                if let Some((_, _, conflict)) = conflict {
+                    tableau.closed = Some((conflict, id));
+                }
+
+                branch.literals.push((&atomic.ident, expect, id));
+            }
+            ExpressionKind::Paren(_) => unreachable!(),
+            ExpressionKind::Unary(unary) => {
+                branch.pending.push((&unary.operand, !expect));
+            }
+            ExpressionKind::Binary(_) => {
+                let alternatives = Self::alternatives(expr, expect);
+
+                if alternatives.len() == 1 {
+                    for &(expr, expect) in alternatives[0].iter().rev() {
+                        branch.pending.push((expr, expect));
+                    }
+                } else {
+                    branch.branching.push((expr, expect, id));
+                }
+            }
+        }
+    }
+
+    /// Returns the formulas added to each branch by the rule for a binary
+    /// expression, a non-branching rule has a single alternative.
+    fn alternatives(expr: &Expression, expect: bool) -> Vec<Vec<(&Expression, bool)>> {
+        let binary = match expr.kind.as_ref() {
+            ExpressionKind::Binary(binary) => binary,
+            _ => unreachable!("only binary expressions have alternatives"),
+        };
+
+        let (lhs, rhs) = (&binary.lhs, &binary.rhs);
+
+        match (binary.operator, expect) {
+            (BinaryOperator::Conjunction(_), true) => vec![vec![(lhs, true), (rhs, true)]],
+            (BinaryOperator::Conjunction(_), false) => vec![vec![(lhs, false)], vec![(rhs, false)]],
+            (BinaryOperator::Disjunction(_), true) => vec![vec![(lhs, true)], vec![(rhs, true)]],
+            (BinaryOperator::Disjunction(_), false) => vec![vec![(lhs, false), (rhs, false)]],
+            (BinaryOperator::Implication(_), true) => vec![vec![(lhs, false)], vec![(rhs, true)]],
+            (BinaryOperator::Implication(_), false) => vec![vec![(lhs, true), (rhs, false)]],
+            (BinaryOperator::Equivalence(_), true)
+            | (BinaryOperator::ExclusiveDisjunction(_), false) => vec![
+                vec![(lhs, true), (rhs, true)],
+                vec![(lhs, false), (rhs, false)],
+            ],
+            (BinaryOperator::Equivalence(_), false)
+            | (BinaryOperator::ExclusiveDisjunction(_), true) => vec![
+                vec![(lhs, true), (rhs, false)],
+                vec![(lhs, false), (rhs, true)],
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Parser, Solutions, TableauBuilder};
+
+    use super::*;
+
+    fn expand(source: &str, expect: bool) -> Tableau {
+        let expr = Parser::new().parse(source).unwrap();
+        TableauExpander::default().expand(&expr, expect)
+    }
+
+    fn size(tableau: &Tableau) -> usize {
+        tableau.expectations.len() + tableau.branches.iter().map(|b| size(b)).sum::<usize>()
+    }
+
+    #[test]
+    fn test_expander() {
+        let sources = [
+            "a",
+            "~a",
+            "a & ~a",
+            "a | b",
+            "(a | b) & ~a",
+            "a -> b",
+            "(a -> b) & a & ~b",
+            "a <-> b",
+            "a ^ b",
+            "(a ^ b) & (a <-> b)",
+            "~(a & b) -> ~a | ~b",
+            "1 & a",
+            "0 | a",
+        ];
+
+        for source in sources {
+            let expr = Parser::new().parse(source).unwrap();
+
+            for expect in [true, false] {
+                let lazy = TableauExpander::default().expand(&expr, expect);
+                let eager = TableauBuilder::default().build_expression(&expr, expect);
+
+                let mut lazy = Solutions::from(&lazy);
+                let mut eager = Solutions::from(&eager);
+                lazy.clean();
+                eager.clean();
+
+                assert_eq!(lazy.is_empty(), eager.is_empty(), "{} : {}", source, expect);
+            }
+        }
+
+        // non-branching rules are applied first
+        let tableau = expand("(a | b) & c & ~c", true);
+        assert!(tableau.is_closed());
+        assert_eq!(tableau.width(), 1);
+
+        let tableau = expand("(a | b) & (c | d) & ~a", true);
+        assert_eq!(
+            tableau.expectations[0].expr.to_string(),
+            "(a | b) & (c | d) & ~a"
+        );
+        assert_eq!(tableau.width(), 4);
+        assert!(
+            size(&tableau)
+                < size(&TableauBuilder::default().build_expression(
+                    &Parser::new().parse("(a | b) & (c | d) & ~a").unwrap(),
+                    true
+                ))
+        );
+
+        let premises = [
+            Parser::new().parse("p -> q").unwrap(),
+            Parser::new().parse("p").unwrap(),
+        ];
+        let conclusion = Parser::new().parse("q").unwrap();
+        let tableau = TableauExpander::default().expand_entailment(&premises, &conclusion);
+        assert!(tableau.is_fully_closed());
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{Error, ExpressionWriter, LatexExpressionWriter, SharedIdMap, Tableau, TableauWriter};
+
+/// Writes a [`Tableau`] as a `forest` environment, leaving the layout to LaTeX.
+///
+/// Requires `\usepackage{forest}` and, when ids are coloured,
+/// `\usepackage{xcolor}`. Unlike [`LatexTableauWriter`](crate::LatexTableauWriter)
+/// the output works with every TeX engine.
+///
+/// # Example
+/// ```rust
+/// # use dare::{ForestTableauWriter, Parser, TableauBuilder, TableauWriter};
+/// let expr = Parser::new().parse("a | b").unwrap();
+/// let tableau = TableauBuilder::default().build_expression(&expr, true);
+///
+/// let mut writer = ForestTableauWriter::default();
+/// writer.write_tableau(&tableau).unwrap();
+///
+/// assert_eq!(
+///     writer.finalize(),
+///     "\\begin{forest}\n\
+///     for tree={align=center}\n\
+///     [{$a \\lor b$ : T}\n  \
+///       [{$a$ : T \\\\ $\\circ$}]\n  \
+///       [{$b$ : T \\\\ $\\circ$}]\n\
+///     ]\n\
+///     \\end{forest}\n"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct ForestTableauWriter {
+    show_ids: bool,
+    id_color: Option<String>,
+    mark_branches: bool,
+    id_map: SharedIdMap,
+    buffer: String,
+}
+
+impl Default for ForestTableauWriter {
+    fn default() -> Self {
+        Self {
+            show_ids: false,
+            id_color: None,
+            mark_branches: true,
+            id_map: SharedIdMap::default(),
+            buffer: String::new(),
+        }
+    }
+}
+
+impl ForestTableauWriter {
+    /// Writes the number of every expectation next to it.
+    pub fn show_ids(&mut self) {
+        self.show_ids = true;
+    }
+
+    /// Writes the numbers in `color`, e.g. `red`, using `xcolor`.
+    pub fn color_ids(&mut self, color: impl Into<String>) {
+        self.show_ids = true;
+        self.id_color = Some(color.into());
+    }
+
+    /// Omits the `×` under closed branches and the `○` under open ones.
+    pub fn hide_markers(&mut self) {
+        self.mark_branches = false;
+    }
+
+    pub fn share_ids(&mut self, id_map: &SharedIdMap) {
+        self.id_map = id_map.clone();
+    }
+
+    fn write_node(&mut self, tableau: &Tableau, depth: usize) -> Result<(), Error> {
+        let mut lines = Vec::new();
+
+        for expectation in &tableau.expectations {
+            let mut writer = LatexExpressionWriter::default();
+            writer.write_expression(&expectation.expr)?;
+
+            let value = if expectation.truth_value { "T" } else { "F" };
+            let mut line = format!("{} : {}", writer.finalize(), value);
+
+            if self.show_ids {
+                let id = self.id_map.get_id(expectation.id);
+
+                match &self.id_color {
+                    Some(color) => line += &format!(" \\quad \\textcolor{{{}}}{{{}}}", color, id),
+                    None => line += &format!(" \\quad {}", id),
+                }
+            }
+
+            lines.push(line);
+        }
+
+        if self.mark_branches && tableau.branches.is_empty() {
+            lines.push(String::from(if tableau.is_closed() {
+                "$\\times$"
+            } else {
+                "$\\circ$"
+            }));
+        }
+
+        // braces keep commas and brackets in the content away from forest
+        self.buffer += &format!("{}[{{{}}}", "  ".repeat(depth), lines.join(" \\\\ "));
+
+        if tableau.branches.is_empty() {
+            self.buffer += "]\n";
+            return Ok(());
+        }
+
+        self.buffer += "\n";
+
+        for branch in &tableau.branches {
+            self.write_node(branch, depth + 1)?;
+        }
+
+        self.buffer += &format!("{}]\n", "  ".repeat(depth));
+
+        Ok(())
+    }
+
+    pub fn finalize(self) -> String {
+        self.buffer
+    }
+}
+
+impl TableauWriter for ForestTableauWriter {
+    type Error = Error;
+
+    fn write_tableau(&mut self, tableau: &Tableau) -> Result<(), Self::Error> {
+        self.buffer += "\\begin{forest}\nfor tree={align=center}\n";
+        self.write_node(tableau, 0)?;
+        self.buffer += "\\end{forest}\n";
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Parser, TableauBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_forest_tableau() {
+        let expr = Parser::new().parse("a & ~a").unwrap();
+        let tableau = TableauBuilder::default().build_expression(&expr, true);
+
+        let mut writer = ForestTableauWriter::default();
+        writer.color_ids("red");
+        writer.write_tableau(&tableau).unwrap();
+
+        let expected = [
+            "\\begin{forest}",
+            "for tree={align=center}",
+            "[{$a \\land \\neg a$ : T \\quad \\textcolor{red}{1}}",
+            "  [{$a$ : T \\quad \\textcolor{red}{2} \\\\ $\\neg a$ : T \\quad \\textcolor{red}{3}}",
+            "    [{$a$ : F \\quad \\textcolor{red}{4} \\\\ $\\times$}]",
+            "  ]",
+            "]",
+            "\\end{forest}",
+            "",
+        ];
+        assert_eq!(writer.finalize(), expected.join("\n"));
+    }
+}
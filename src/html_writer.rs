@@ -0,0 +1,319 @@
+use crate::{
+    BinaryExpression, BinaryOperator, Error, Expression, ExpressionKind, ExpressionWriter,
+    SharedIdMap, Tableau, TableauWriter, TruthTable, TruthTableWriter, UnaryExpression,
+    UnaryOperator,
+};
+
+/// Escapes `text` for use in HTML text and attributes.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Writes an [`Expression`] as an inline MathML `<math>` element, every
+/// unary, binary and parenthesised subexpression is grouped in an `<mrow>`.
+///
+/// # Example
+/// ```rust
+/// # use dare::{ExpressionWriter, HtmlExpressionWriter, Parser};
+/// let expr = Parser::new().parse("~(a & b)").unwrap();
+///
+/// let mut writer = HtmlExpressionWriter::default();
+/// writer.write_expression(&expr).unwrap();
+///
+/// assert_eq!(
+///     writer.finalize(),
+///     "<math><mrow><mo>¬</mo><mrow><mo>(</mo><mrow><mi>a</mi><mo>∧</mo><mi>b</mi></mrow><mo>)</mo></mrow></mrow></math>"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct HtmlExpressionWriter {
+    buffer: String,
+}
+
+impl HtmlExpressionWriter {
+    pub fn write_unary(&mut self, unary: &UnaryExpression) -> Result<(), Error> {
+        match unary.operator {
+            UnaryOperator::Negation(_) => self.buffer += "<mrow><mo>¬</mo>",
+        }
+
+        self.write_expression(&unary.operand)?;
+        self.buffer += "</mrow>";
+        Ok(())
+    }
+
+    pub fn write_binary(&mut self, binary: &BinaryExpression) -> Result<(), Error> {
+        self.buffer += "<mrow>";
+        self.write_expression(&binary.lhs)?;
+
+        match binary.operator {
+            BinaryOperator::Conjunction(_) => self.buffer += "<mo>∧</mo>",
+            BinaryOperator::Disjunction(_) => self.buffer += "<mo>∨</mo>",
+            BinaryOperator::ExclusiveDisjunction(_) => self.buffer += "<mo>⊕</mo>",
+            BinaryOperator::Implication(_) => self.buffer += "<mo>→</mo>",
+            BinaryOperator::Equivalence(_) => self.buffer += "<mo>↔</mo>",
+        }
+
+        self.write_expression(&binary.rhs)?;
+        self.buffer += "</mrow>";
+        Ok(())
+    }
+
+    pub fn finalize(self) -> String {
+        format!("<math>{}</math>", self.buffer)
+    }
+}
+
+impl ExpressionWriter for HtmlExpressionWriter {
+    type Error = Error;
+
+    fn write_expression(&mut self, expr: &Expression) -> Result<(), Self::Error> {
+        match expr.kind.as_ref() {
+            ExpressionKind::TruthValue(truth_value) => {
+                let value = if truth_value.value { "T" } else { "F" };
+                self.buffer += &format!("<mtext>{}</mtext>", value);
+            }
+            ExpressionKind::Atomic(atomic) => {
+                self.buffer += &format!("<mi>{}</mi>", escape(&atomic.ident));
+            }
+            ExpressionKind::Paren(paren) => {
+                self.buffer += "<mrow><mo>(</mo>";
+                self.write_expression(&paren.expression)?;
+                self.buffer += "<mo>)</mo></mrow>";
+            }
+            ExpressionKind::Unary(unary) => self.write_unary(unary)?,
+            ExpressionKind::Binary(binary) => self.write_binary(binary)?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a [`TruthTable`] as an HTML `<table>`, every cell is given the
+/// class `true` or `false` so it can be styled.
+///
+/// # Example
+/// ```rust
+/// # use dare::{HtmlTruthTableWriter, Parser, TruthTable, TruthTableWriter};
+/// let expr = Parser::new().parse("~a").unwrap();
+/// let table = TruthTable::new(&expr).unwrap();
+///
+/// let mut writer = HtmlTruthTableWriter::default();
+/// writer.write_truth_table(&table).unwrap();
+///
+/// let expected = [
+///     "<table class=\"truth-table\">",
+///     "  <thead>",
+///     "    <tr><th><math><mi>a</mi></math></th><th><math><mrow><mo>¬</mo><mi>a</mi></mrow></math></th></tr>",
+///     "  </thead>",
+///     "  <tbody>",
+///     "    <tr><td class=\"true\">T</td><td class=\"false\">F</td></tr>",
+///     "    <tr><td class=\"false\">F</td><td class=\"true\">T</td></tr>",
+///     "  </tbody>",
+///     "</table>",
+///     "",
+/// ];
+/// assert_eq!(writer.finalize(), expected.join("\n"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct HtmlTruthTableWriter {
+    buffer: String,
+}
+
+impl HtmlTruthTableWriter {
+    pub fn finalize(self) -> String {
+        self.buffer
+    }
+}
+
+impl TruthTableWriter for HtmlTruthTableWriter {
+    type Error = Error;
+
+    fn write_truth_table(&mut self, table: &TruthTable) -> Result<(), Self::Error> {
+        self.buffer += "<table class=\"truth-table\">\n  <thead>\n    <tr>";
+
+        for variable in table.variables() {
+            self.buffer += &format!("<th><math><mi>{}</mi></math></th>", escape(variable));
+        }
+
+        for column in table.columns() {
+            let mut writer = HtmlExpressionWriter::default();
+            writer.write_expression(column)?;
+            self.buffer += &format!("<th>{}</th>", writer.finalize());
+        }
+
+        self.buffer += "</tr>\n  </thead>\n  <tbody>\n";
+
+        for row in table.rows() {
+            self.buffer += "    <tr>";
+
+            for &value in row.assignment.iter().chain(row.values.iter()) {
+                self.buffer += if value {
+                    "<td class=\"true\">T</td>"
+                } else {
+                    "<td class=\"false\">F</td>"
+                };
+            }
+
+            self.buffer += "</tr>\n";
+        }
+
+        self.buffer += "  </tbody>\n</table>\n";
+
+        Ok(())
+    }
+}
+
+/// Writes a [`Tableau`] as nested `<ul>` lists, one `<li>` per branch.
+///
+/// Every expectation is a `<div class="expectation">` followed by its number,
+/// a branch expanding an expectation carries its number in `data-expands`
+/// and leaves end in a `closed` or `open` marker.
+///
+/// # Example
+/// ```rust
+/// # use dare::{HtmlTableauWriter, Parser, TableauBuilder, TableauWriter};
+/// let expr = Parser::new().parse("~a").unwrap();
+/// let tableau = TableauBuilder::default().build_expression(&expr, true);
+///
+/// let mut writer = HtmlTableauWriter::default();
+/// writer.write_tableau(&tableau).unwrap();
+///
+/// let expected = [
+///     "<ul class=\"tableau\">",
+///     "  <li>",
+///     "    <div class=\"expectation\"><math><mrow><mo>¬</mo><mi>a</mi></mrow></math> : T <span class=\"id\">1</span></div>",
+///     "    <ul>",
+///     "      <li data-expands=\"1\">",
+///     "        <div class=\"expectation\"><math><mi>a</mi></math> : F <span class=\"id\">2</span></div>",
+///     "        <div class=\"open\">○</div>",
+///     "      </li>",
+///     "    </ul>",
+///     "  </li>",
+///     "</ul>",
+///     "",
+/// ];
+/// assert_eq!(writer.finalize(), expected.join("\n"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct HtmlTableauWriter {
+    id_map: SharedIdMap,
+    buffer: String,
+}
+
+impl HtmlTableauWriter {
+    pub fn share_ids(&mut self, id_map: &SharedIdMap) {
+        self.id_map = id_map.clone();
+    }
+
+    fn write_node(
+        &mut self,
+        tableau: &Tableau,
+        expands: Option<u32>,
+        depth: usize,
+    ) -> Result<(), Error> {
+        let indent = "  ".repeat(depth);
+
+        match expands {
+            Some(id) => {
+                let id = self.id_map.get_id(id);
+                self.buffer += &format!("{}<li data-expands=\"{}\">\n", indent, id);
+            }
+            None => self.buffer += &format!("{}<li>\n", indent),
+        }
+
+        for expectation in &tableau.expectations {
+            let mut writer = HtmlExpressionWriter::default();
+            writer.write_expression(&expectation.expr)?;
+
+            let value = if expectation.truth_value { "T" } else { "F" };
+            self.buffer += &format!(
+                "{}  <div class=\"expectation\">{} : {} <span class=\"id\">{}</span></div>\n",
+                indent,
+                writer.finalize(),
+                value,
+                self.id_map.get_id(expectation.id)
+            );
+        }
+
+        if tableau.branches.is_empty() {
+            self.buffer += &format!(
+                "{}  {}\n",
+                indent,
+                if tableau.is_closed() {
+                    "<div class=\"closed\">×</div>"
+                } else {
+                    "<div class=\"open\">○</div>"
+                }
+            );
+        } else {
+            self.buffer += &format!("{}  <ul>\n", indent);
+
+            for branch in &tableau.branches {
+                self.write_node(branch, Some(branch.expectation), depth + 2)?;
+            }
+
+            self.buffer += &format!("{}  </ul>\n", indent);
+        }
+
+        self.buffer += &format!("{}</li>\n", indent);
+
+        Ok(())
+    }
+
+    pub fn finalize(self) -> String {
+        self.buffer
+    }
+}
+
+impl TableauWriter for HtmlTableauWriter {
+    type Error = Error;
+
+    fn write_tableau(&mut self, tableau: &Tableau) -> Result<(), Self::Error> {
+        self.buffer += "<ul class=\"tableau\">\n";
+        self.write_node(tableau, None, 1)?;
+        self.buffer += "</ul>\n";
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Parser, TableauBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_html_tableau() {
+        let expr = Parser::new().parse("a_1 & ~a_1").unwrap();
+        let tableau = TableauBuilder::default().build_expression(&expr, true);
+
+        let mut writer = HtmlTableauWriter::default();
+        writer.write_tableau(&tableau).unwrap();
+
+        let expected = [
+            "<ul class=\"tableau\">",
+            "  <li>",
+            "    <div class=\"expectation\"><math><mrow><mi>a_1</mi><mo>∧</mo><mrow><mo>¬</mo><mi>a_1</mi></mrow></mrow></math> : T <span class=\"id\">1</span></div>",
+            "    <ul>",
+            "      <li data-expands=\"1\">",
+            "        <div class=\"expectation\"><math><mi>a_1</mi></math> : T <span class=\"id\">2</span></div>",
+            "        <div class=\"expectation\"><math><mrow><mo>¬</mo><mi>a_1</mi></mrow></math> : T <span class=\"id\">3</span></div>",
+            "        <ul>",
+            "          <li data-expands=\"3\">",
+            "            <div class=\"expectation\"><math><mi>a_1</mi></math> : F <span class=\"id\">4</span></div>",
+            "            <div class=\"closed\">×</div>",
+            "          </li>",
+            "        </ul>",
+            "      </li>",
+            "    </ul>",
+            "  </li>",
+            "</ul>",
+            "",
+        ];
+        assert_eq!(writer.finalize(), expected.join("\n"));
+    }
+}
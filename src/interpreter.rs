@@ -1,12 +1,13 @@
 use crate::{
-    AtomicExpression, BinaryOperator, Error, Expression, ExpressionKind, TruthValueExpression,
-    UnaryOperator,
+    AtomicExpression, BinaryOperator, Definition, Error, ErrorKind, Expression, ExpressionKind,
+    ParenExpression, TruthValueExpression, UnaryOperator,
 };
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, Default)]
 pub struct Interpreter {
     pub variables: HashMap<String, bool>,
+    pub definitions: HashMap<String, Definition>,
     pub atomic_expressions: Vec<AtomicExpression>,
     pub errors: Vec<Error>,
 }
@@ -16,10 +17,132 @@ impl Interpreter {
         Self::default()
     }
 
+    /// Adds `definition` to the environment, so later references to its name
+    /// expand to its expression.
+    ///
+    /// Fails if the name is already defined or if the definition refers to
+    /// itself, either directly or through other definitions.
+    pub fn define(&mut self, definition: &Definition) -> Result<(), Error> {
+        let name = &definition.name.ident;
+
+        if let Some(previous) = self.definitions.get(name) {
+            let error = Error::new()
+                .with_kind(ErrorKind::Redefinition)
+                .with_msg(format!("redefinition of '{}'", name))
+                .with_span(definition.name.span)
+                .with_msg("previously defined here")
+                .with_span(previous.name.span)
+                .with_help("definitions can't be changed, use a new name instead");
+            self.errors.push(error.clone());
+            return Err(error);
+        }
+
+        if let Err(error) = self.check_cycle(name, &definition.expression, &mut Vec::new()) {
+            self.errors.push(error.clone());
+            return Err(error);
+        }
+
+        self.definitions.insert(name.clone(), definition.clone());
+
+        Ok(())
+    }
+
+    /// Fails if `expression` refers to `name`, following definitions.
+    ///
+    /// `chain` contains the references followed to reach `expression`.
+    fn check_cycle<'a>(
+        &'a self,
+        name: &str,
+        expression: &'a Expression,
+        chain: &mut Vec<&'a AtomicExpression>,
+    ) -> Result<(), Error> {
+        match expression.kind.as_ref() {
+            ExpressionKind::Atomic(atomic) if atomic.ident == name => {
+                let error = match chain.first() {
+                    None => Error::new()
+                        .with_kind(ErrorKind::CyclicDefinition)
+                        .with_msg(format!("definition of '{}' refers to itself", name))
+                        .with_span(atomic.span),
+                    Some(first) => {
+                        let mut error = Error::new()
+                            .with_kind(ErrorKind::CyclicDefinition)
+                            .with_msg(format!("cyclic definition of '{}'", name))
+                            .with_span(first.span);
+
+                        for reference in chain.iter().skip(1).chain([&atomic]) {
+                            error = error
+                                .with_msg(format!("which refers to '{}'", reference.ident))
+                                .with_span(reference.span);
+                        }
+
+                        error
+                    }
+                };
+
+                Err(error)
+            }
+            ExpressionKind::Atomic(atomic) => match self.definitions.get(&atomic.ident) {
+                Some(definition) => {
+                    chain.push(atomic);
+                    self.check_cycle(name, &definition.expression, chain)?;
+                    chain.pop();
+                    Ok(())
+                }
+                None => Ok(()),
+            },
+            ExpressionKind::TruthValue(_) => Ok(()),
+            ExpressionKind::Paren(paren) => self.check_cycle(name, &paren.expression, chain),
+            ExpressionKind::Unary(unary) => self.check_cycle(name, &unary.operand, chain),
+            ExpressionKind::Binary(binary) => {
+                self.check_cycle(name, &binary.lhs, chain)?;
+                self.check_cycle(name, &binary.rhs, chain)
+            }
+        }
+    }
+
+    /// Returns `expression` with every defined name replaced by its
+    /// parenthesized definition.
+    pub fn expand(&self, expression: &Expression) -> Expression {
+        let kind = match expression.kind.as_ref() {
+            ExpressionKind::Atomic(atomic) => match self.definitions.get(&atomic.ident) {
+                Some(definition) => ExpressionKind::Paren(ParenExpression {
+                    expression: self.expand(&definition.expression),
+                }),
+                None => return expression.clone(),
+            },
+            ExpressionKind::TruthValue(_) => return expression.clone(),
+            ExpressionKind::Paren(paren) => ExpressionKind::Paren(ParenExpression {
+                expression: self.expand(&paren.expression),
+            }),
+            ExpressionKind::Unary(unary) => {
+                let mut unary = unary.clone();
+                unary.operand = self.expand(&unary.operand);
+                ExpressionKind::Unary(unary)
+            }
+            ExpressionKind::Binary(binary) => {
+                let mut binary = binary.clone();
+                binary.lhs = self.expand(&binary.lhs);
+                binary.rhs = self.expand(&binary.rhs);
+                ExpressionKind::Binary(binary)
+            }
+        };
+
+        Expression {
+            kind: Box::new(kind),
+            span: expression.span,
+        }
+    }
+
     pub fn extract_atomic_expressions(&mut self, expression: &Expression) {
         match expression.kind.as_ref() {
             ExpressionKind::Atomic(atomic_expression) => {
-                self.atomic_expressions.push(atomic_expression.clone())
+                match self.definitions.get(&atomic_expression.ident) {
+                    Some(definition) => {
+                        let expression = definition.expression.clone();
+                        self.extract_atomic_expressions(&expression)
+                    }
+                    None => self.atomic_expressions.push(atomic_expression.clone()),
+                }
             }
             ExpressionKind::Unary(unary_expression) => {
                 self.extract_atomic_expressions(&unary_expression.operand)
@@ -39,6 +162,16 @@ impl Interpreter {
         match expression.kind.as_ref() {
             ExpressionKind::Paren(expression) => self.interpret(&expression.expression),
             ExpressionKind::Atomic(expression) => {
+                if let Some(definition) = self.definitions.get(&expression.ident) {
+                    let definition = definition.expression.clone();
+                    let value = self.interpret(&definition)?;
+
+                    return Ok(TruthValueExpression {
+                        value: value.value,
+                        span: expression.span,
+                    });
+                }
+
                 self.atomic_expressions.push(expression.clone());
 
                 if let Some(value) = self.variables.get(&expression.ident) {
@@ -48,6 +181,7 @@ impl Interpreter {
                     })
                 } else {
                     let error = Error::new()
+                        .with_kind(ErrorKind::UnknownIdentifier)
                         .with_msg("unknown identifier")
                         .with_span(expression.span.clone());
                     self.errors.push(error.clone());
@@ -64,54 +198,31 @@ impl Interpreter {
                 }
             }
             ExpressionKind::Binary(expression) => {
+                let lhs = self.interpret(&expression.lhs)?;
                 let rhs = self.interpret(&expression.rhs)?;
 
                 match expression.operator {
-                    // FIXME(javad): PLEASE FIX
-                    /*
-                    BinaryOperator::Assignment(_) => {
-                        if let ExpressionKind::Atomic(atomic) = expression.lhs.kind.as_ref() {
-                            self.variables.insert(atomic.ident.clone(), rhs.value);
-                            Ok(TruthValueExpression {
-                                value: rhs.value,
-                                span: expression.operator_span.clone(),
-                            })
-                        } else {
-                            let error = Error::new()
-                                .with_msg("invalid assignment")
-                                .with_span(expression.operator_span.clone());
-                            self.errors.push(error.clone());
-                            Err(error)
-                        }
-                    }
-                    */
-                    operator => {
-                        let lhs = self.interpret(&expression.lhs)?;
-
-                        match operator {
-                            BinaryOperator::Conjunction(_) => Ok(TruthValueExpression {
-                                value: lhs.value && rhs.value,
-                                span: expression.operator_span.clone(),
-                            }),
-                            BinaryOperator::Disjunction(_) => Ok(TruthValueExpression {
-                                value: lhs.value || rhs.value,
-                                span: expression.operator_span.clone(),
-                            }),
-                            BinaryOperator::ExclusiveDisjunction(_) => Ok(TruthValueExpression {
-                                value: lhs.value ^ rhs.value,
-                                span: expression.operator_span.clone(),
-                            }),
-
-                            BinaryOperator::Implication(_) => Ok(TruthValueExpression {
-                                value: !lhs.value || rhs.value,
-                                span: expression.operator_span.clone(),
-                            }),
-                            BinaryOperator::Equivalence(_) => Ok(TruthValueExpression {
-                                value: lhs.value == rhs.value,
-                                span: expression.operator_span.clone(),
-                            }),
-                        }
-                    }
+                    BinaryOperator::Conjunction(_) => Ok(TruthValueExpression {
+                        value: lhs.value && rhs.value,
+                        span: expression.operator_span.clone(),
+                    }),
+                    BinaryOperator::Disjunction(_) => Ok(TruthValueExpression {
+                        value: lhs.value || rhs.value,
+                        span: expression.operator_span.clone(),
+                    }),
+                    BinaryOperator::ExclusiveDisjunction(_) => Ok(TruthValueExpression {
+                        value: lhs.value ^ rhs.value,
+                        span: expression.operator_span.clone(),
+                    }),
+
+                    BinaryOperator::Implication(_) => Ok(TruthValueExpression {
+                        value: !lhs.value || rhs.value,
+                        span: expression.operator_span.clone(),
+                    }),
+                    BinaryOperator::Equivalence(_) => Ok(TruthValueExpression {
+                        value: lhs.value == rhs.value,
+                        span: expression.operator_span.clone(),
+                    }),
                 }
             }
             ExpressionKind::TruthValue(truthvalue_expr) => Ok(TruthValueExpression {
@@ -129,7 +240,7 @@ impl Interpreter {
 // Compare this snippet from src\parser.rs:
 #[cfg(test)]
 mod tests {
-    use crate::Parser;
+    use crate::{Parser, Span};
 
     use super::*;
 
@@ -201,4 +312,57 @@ mod tests {
         interpreter_test_identical!("a & (a | b) == a", true, true);
         interpreter_test_identical!("a | (a & b) == a", true, true);
     }
+
+    #[test]
+    fn test_definitions() {
+        let parser = Parser::new();
+        let mut interpreter = Interpreter::new();
+
+        macro_rules! define {
+            ($source:literal) => {{
+                let mut tokens = crate::TokenStream::parse($source).unwrap();
+                let definition = parser.parse_definition(&mut tokens).unwrap();
+                interpreter.define(&definition)
+            }};
+        }
+
+        define!("p := q & r").unwrap();
+        define!("s : p | t").unwrap();
+
+        let expr = parser.parse("s -> q").unwrap();
+        assert_eq!(interpreter.expand(&expr).to_string(), "((q & r) | t) -> q");
+
+        interpreter.extract_atomic_expressions(&expr);
+        let variables = interpreter
+            .atomic_expressions
+            .iter()
+            .map(|atomic| atomic.ident.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(variables, ["q", "r", "t", "q"]);
+
+        interpreter.variables.insert(String::from("q"), true);
+        interpreter.variables.insert(String::from("r"), false);
+        interpreter.variables.insert(String::from("t"), true);
+        assert!(interpreter.interpret(&expr).unwrap().value);
+        interpreter.variables.insert(String::from("q"), false);
+        assert!(!interpreter.interpret(&expr).unwrap().value);
+
+        let error = define!("p := r").unwrap_err();
+        assert_eq!(error.messages()[0], "redefinition of 'p'");
+        assert_eq!(error.kind(), ErrorKind::Redefinition);
+        assert_eq!(error.spans()[1], Span::new(0, 1));
+
+        let error = define!("u := u | q").unwrap_err();
+        assert_eq!(error.messages()[0], "definition of 'u' refers to itself");
+        assert_eq!(error.spans()[0], Span::new(5, 1));
+
+        define!("v := w").unwrap();
+        let error = define!("w := ~v").unwrap_err();
+        assert_eq!(error.messages()[0], "cyclic definition of 'w'");
+        assert_eq!(error.kind(), ErrorKind::CyclicDefinition);
+        assert_eq!(error.spans().len(), 2);
+
+        assert_eq!(interpreter.errors.len(), 3);
+        assert!(!interpreter.definitions.contains_key("w"));
+    }
 }
@@ -0,0 +1,145 @@
+use crate::{Expectation, Tableau};
+
+/// A single element of a [`TableauLayout`], positioned in picture
+/// coordinates where `y` grows upwards.
+#[derive(Clone, Debug)]
+pub(crate) enum LayoutItem<'a> {
+    /// An expectation centered on `x`, its id is only drawn if `show_id` is set.
+    Row {
+        x: f32,
+        y: f32,
+        expectation: &'a Expectation,
+        show_id: bool,
+    },
+    /// The mark under a leaf, `×` if the branch is closed and `○` otherwise.
+    Marker { x: f32, y: f32, closed: bool },
+    /// A quadratic curve from a node to one of its branches, labelled with
+    /// the id of the expanded expectation at `label` if any.
+    Edge {
+        from: (f32, f32),
+        control: (f32, f32),
+        to: (f32, f32),
+        label: Option<(f32, f32, u32)>,
+    },
+}
+
+/// The positions of every row, marker and branch line of a [`Tableau`],
+/// shared by the writers that draw tableaux themselves.
+#[derive(Clone, Debug)]
+pub(crate) struct TableauLayout<'a> {
+    pub width: f32,
+    pub height: f32,
+    pub items: Vec<LayoutItem<'a>>,
+}
+
+impl<'a> TableauLayout<'a> {
+    pub const BRANCH_WIDTH: f32 = 40.0;
+    pub const BRANCH_HEIGHT: f32 = 30.0;
+    pub const ROW_HEIGHT: f32 = 10.0;
+
+    pub fn new(tableau: &'a Tableau, show_all_ids: bool) -> Self {
+        let mut layout = Self {
+            width: Self::tableau_width(tableau),
+            height: Self::tableau_height(tableau),
+            items: Vec::new(),
+        };
+
+        layout.place(tableau, 0.0, 0.0, show_all_ids);
+
+        layout
+    }
+
+    fn tableau_width(tableau: &Tableau) -> f32 {
+        tableau.width() as f32 * Self::BRANCH_WIDTH
+    }
+
+    fn tableau_height(tableau: &Tableau) -> f32 {
+        let mut height = 0.0;
+
+        height += tableau.expectations.len() as f32 * Self::ROW_HEIGHT;
+
+        // leaves are marked as closed or open
+        if tableau.branches.is_empty() {
+            height += Self::ROW_HEIGHT;
+        }
+
+        let mut max_height = 0.0f32;
+        for branch in &tableau.branches {
+            max_height = max_height.max(Self::tableau_height(branch) + Self::BRANCH_HEIGHT);
+        }
+
+        height += max_height;
+
+        height
+    }
+
+    /// Places `tableau` with its first row offset by `x` and `y` from the top
+    /// center of the picture.
+    fn place(&mut self, tableau: &'a Tableau, x: f32, mut y: f32, show_all_ids: bool) {
+        let center = x + self.width / 2.0;
+
+        for expectation in &tableau.expectations {
+            let terminates = expectation.expr.terminates();
+
+            self.items.push(LayoutItem::Row {
+                x: center,
+                y: y + self.height,
+                expectation,
+                show_id: !tableau.solves_expectation(expectation.id) && !terminates || show_all_ids,
+            });
+
+            y -= Self::ROW_HEIGHT;
+        }
+
+        if tableau.branches.is_empty() {
+            self.items.push(LayoutItem::Marker {
+                x: center,
+                y: y + self.height,
+                closed: tableau.is_closed(),
+            });
+        }
+
+        for (i, branch) in tableau.branches.iter().enumerate() {
+            let width = Self::tableau_width(branch);
+            let mut width_mod = i as f32 / (tableau.branches.len() - 1) as f32 * 2.0 - 1.0;
+
+            if width_mod.is_nan() {
+                width_mod = 0.0;
+            }
+
+            let calculated_width = Self::tableau_width(tableau);
+            width_mod *= 1.0 - width / calculated_width;
+
+            let branch_x = x + calculated_width * width_mod;
+            let branch_y = y - Self::BRANCH_HEIGHT;
+
+            let mid_x = (branch_x + x) / 2.0 + self.width / 2.0;
+            let mid_y = (branch_y + 8.0 + y) / 2.0 + self.height;
+
+            // only the last branch is labelled, as every branch expands the
+            // same expectation
+            let label = if (!tableau.has_expectation(branch.expectation) || show_all_ids)
+                && i == tableau.branches.len() - 1
+            {
+                let label_y = if tableau.branches.len() == 1 {
+                    mid_y
+                } else {
+                    mid_y + 5.0
+                };
+
+                Some((mid_x + 10.0, label_y, branch.expectation))
+            } else {
+                None
+            };
+
+            self.items.push(LayoutItem::Edge {
+                from: (center, y + self.height),
+                control: (mid_x, mid_y),
+                to: (branch_x + self.width / 2.0, branch_y + 8.0 + self.height),
+                label,
+            });
+
+            self.place(branch, branch_x, branch_y, show_all_ids);
+        }
+    }
+}
@@ -3,25 +3,60 @@
 //! Dare is a crate for parsing and solving logical expressions.
 
 mod ast;
+mod check;
+mod cnf;
+mod csv_writer;
+mod diagnostic;
+mod dimacs_reader;
+mod dimacs_writer;
+mod dot_writer;
 mod error;
+mod expander;
+mod forest_writer;
+mod html_writer;
 mod interpreter;
 mod latex_writer;
+mod layout;
+mod markdown_writer;
+mod normal_form;
 mod parser;
+mod sat;
 mod solve;
 mod span;
+mod svg_writer;
 mod tableau;
+#[cfg(test)]
+mod test_util;
+mod text_writer;
 mod token;
 mod token_stream;
+mod truth_table;
 mod writer;
 
 pub use ast::*;
+pub use check::*;
+pub use cnf::*;
+pub use csv_writer::*;
+pub use diagnostic::*;
+pub use dimacs_reader::*;
+pub use dimacs_writer::*;
+pub use dot_writer::*;
 pub use error::*;
+pub use expander::*;
+pub use forest_writer::*;
+pub use html_writer::*;
 pub use interpreter::*;
 pub use latex_writer::*;
+pub use markdown_writer::*;
+pub use normal_form::*;
 pub use parser::*;
+pub use sat::*;
 pub use solve::*;
 pub use span::*;
+pub use svg_writer::*;
 pub use tableau::*;
+pub use text_writer::*;
 pub use token::*;
 pub use token_stream::*;
+pub use truth_table::*;
 pub use writer::*;
@@ -0,0 +1,235 @@
+use crate::{
+    BinaryExpression, BinaryOperator, Error, Expression, ExpressionKind, ExpressionWriter,
+    Solution, Solutions, SolutionsWriter, TruthTable, TruthTableWriter, UnaryExpression,
+    UnaryOperator,
+};
+
+/// Writes an [`Expression`] as inline code using the Unicode connectives.
+///
+/// # Example
+/// ```rust
+/// # use dare::{ExpressionWriter, MarkdownExpressionWriter, Parser};
+/// let expr = Parser::new().parse("~a & (b -> c)").unwrap();
+///
+/// let mut writer = MarkdownExpressionWriter::default();
+/// writer.write_expression(&expr).unwrap();
+///
+/// assert_eq!(writer.finalize(), "`¬a ∧ (b → c)`");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MarkdownExpressionWriter {
+    buffer: String,
+}
+
+impl MarkdownExpressionWriter {
+    pub fn write_unary(&mut self, unary: &UnaryExpression) -> Result<(), Error> {
+        match unary.operator {
+            UnaryOperator::Negation(_) => self.buffer += "¬",
+        }
+
+        self.write_expression(&unary.operand)
+    }
+
+    pub fn write_binary(&mut self, binary: &BinaryExpression) -> Result<(), Error> {
+        self.write_expression(&binary.lhs)?;
+
+        match binary.operator {
+            BinaryOperator::Conjunction(_) => self.buffer += " ∧ ",
+            BinaryOperator::Disjunction(_) => self.buffer += " ∨ ",
+            BinaryOperator::ExclusiveDisjunction(_) => self.buffer += " ⊕ ",
+            BinaryOperator::Implication(_) => self.buffer += " → ",
+            BinaryOperator::Equivalence(_) => self.buffer += " ↔ ",
+        }
+
+        self.write_expression(&binary.rhs)
+    }
+
+    pub fn finalize(self) -> String {
+        format!("`{}`", self.buffer)
+    }
+}
+
+impl ExpressionWriter for MarkdownExpressionWriter {
+    type Error = Error;
+
+    fn write_expression(&mut self, expr: &Expression) -> Result<(), Self::Error> {
+        match expr.kind.as_ref() {
+            ExpressionKind::TruthValue(truth_value) => {
+                self.buffer += if truth_value.value { "T" } else { "F" };
+            }
+            ExpressionKind::Atomic(atomic) => self.buffer += &atomic.ident,
+            ExpressionKind::Paren(paren) => {
+                self.buffer += "(";
+                self.write_expression(&paren.expression)?;
+                self.buffer += ")";
+            }
+            ExpressionKind::Unary(unary) => self.write_unary(unary)?,
+            ExpressionKind::Binary(binary) => self.write_binary(binary)?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a [`TruthTable`] as a GitHub-Markdown pipe table.
+///
+/// The layout matches the operator tables in the README, with every
+/// subexpression column written like [`MarkdownExpressionWriter`] does.
+///
+/// # Example
+/// ```rust
+/// # use dare::{MarkdownTruthTableWriter, Parser, TruthTable, TruthTableWriter};
+/// let expr = Parser::new().parse("a -> b").unwrap();
+/// let table = TruthTable::new(&expr).unwrap();
+///
+/// let mut writer = MarkdownTruthTableWriter::default();
+/// writer.write_truth_table(&table).unwrap();
+///
+/// let expected = [
+///     "| a | b | `a → b` |",
+///     "| - | - | ------- |",
+///     "| T | T | T       |",
+///     "| T | F | F       |",
+///     "| F | T | T       |",
+///     "| F | F | T       |",
+///     "",
+/// ];
+/// assert_eq!(writer.finalize(), expected.join("\n"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MarkdownTruthTableWriter {
+    buffer: String,
+}
+
+impl MarkdownTruthTableWriter {
+    fn write_row(&mut self, cells: &[String], widths: &[usize]) {
+        self.buffer += "|";
+
+        for (cell, width) in cells.iter().zip(widths) {
+            self.buffer += " ";
+            self.buffer += cell;
+            self.buffer += &" ".repeat(width - cell.chars().count());
+            self.buffer += " |";
+        }
+
+        self.buffer += "\n";
+    }
+
+    pub fn finalize(self) -> String {
+        self.buffer
+    }
+}
+
+impl TruthTableWriter for MarkdownTruthTableWriter {
+    type Error = Error;
+
+    fn write_truth_table(&mut self, table: &TruthTable) -> Result<(), Self::Error> {
+        let mut header = table.variables().to_vec();
+
+        for column in table.columns() {
+            let mut writer = MarkdownExpressionWriter::default();
+            writer.write_expression(column)?;
+
+            // a pipe would end the cell even inside inline code
+            header.push(writer.finalize().replace('|', "\\|"));
+        }
+
+        let widths = header
+            .iter()
+            .map(|cell| cell.chars().count())
+            .collect::<Vec<_>>();
+
+        self.write_row(&header, &widths);
+
+        let separator = widths
+            .iter()
+            .map(|&width| "-".repeat(width))
+            .collect::<Vec<_>>();
+        self.write_row(&separator, &widths);
+
+        for row in table.rows() {
+            let cells = row
+                .assignment
+                .iter()
+                .chain(row.values.iter())
+                .map(|&value| String::from(if value { "T" } else { "F" }))
+                .collect::<Vec<_>>();
+
+            self.write_row(&cells, &widths);
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes [`Solutions`] as one bullet list of assignments per [`Solution`].
+///
+/// # Example
+/// ```rust
+/// # use dare::{MarkdownSolutionsWriter, Parser, Solutions, SolutionsWriter, TableauBuilder};
+/// let expr = Parser::new().parse("a & ~b").unwrap();
+/// let tableau = TableauBuilder::default().build_expression(&expr, true);
+///
+/// let mut writer = MarkdownSolutionsWriter::default();
+/// writer.write_solutions(&Solutions::from(&tableau)).unwrap();
+///
+/// assert_eq!(writer.finalize(), "**Solution #0**\n\n- `b`: F\n- `a`: T\n");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MarkdownSolutionsWriter {
+    buffer: String,
+}
+
+impl MarkdownSolutionsWriter {
+    /// Writes the assignments of a single solution, e.g. a counter-example.
+    pub fn write_solution(&mut self, solution: &Solution) {
+        for (variable, value) in solution.iter() {
+            let value = if value { "T" } else { "F" };
+            self.buffer += &format!("- `{}`: {}\n", variable, value);
+        }
+    }
+
+    pub fn finalize(self) -> String {
+        self.buffer
+    }
+}
+
+impl SolutionsWriter for MarkdownSolutionsWriter {
+    type Error = Error;
+
+    fn write_solutions(&mut self, solutions: &Solutions) -> Result<(), Self::Error> {
+        if solutions.is_empty() {
+            self.buffer += "No solutions found.\n";
+        }
+
+        for (i, solution) in solutions.iter().enumerate() {
+            if i > 0 {
+                self.buffer += "\n";
+            }
+
+            self.buffer += &format!("**Solution #{}**\n\n", i);
+            self.write_solution(solution);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_markdown_truth_table_header() {
+        let expr = Parser::new().parse("a | b").unwrap();
+        let table = TruthTable::new(&expr).unwrap();
+
+        let mut writer = MarkdownTruthTableWriter::default();
+        writer.write_truth_table(&table).unwrap();
+
+        let output = writer.finalize();
+        assert_eq!(output.lines().next(), Some("| a | b | `a ∨ b` |"));
+    }
+}
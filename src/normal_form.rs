@@ -0,0 +1,349 @@
+use crate::{
+    AtomicExpression, BinaryExpression, BinaryOperator, Expression, ExpressionKind,
+    ParenExpression, Span, TruthValueExpression, UnaryExpression, UnaryOperator,
+};
+
+/// A literal of a clause, an atomic proposition or its negation.
+#[derive(Clone, Debug)]
+struct Literal {
+    atomic: AtomicExpression,
+    positive: bool,
+}
+
+impl Literal {
+    fn is_complement(&self, other: &Self) -> bool {
+        self.atomic.ident == other.atomic.ident && self.positive != other.positive
+    }
+
+    fn is_same(&self, other: &Self) -> bool {
+        self.atomic.ident == other.atomic.ident && self.positive == other.positive
+    }
+}
+
+fn truth_value(value: bool, span: Span) -> Expression {
+    Expression {
+        kind: Box::new(ExpressionKind::TruthValue(TruthValueExpression {
+            value,
+            span,
+        })),
+        span,
+    }
+}
+
+fn literal(literal: &Literal) -> Expression {
+    let span = literal.atomic.span;
+    let atomic = Expression {
+        kind: Box::new(ExpressionKind::Atomic(literal.atomic.clone())),
+        span,
+    };
+
+    if literal.positive {
+        return atomic;
+    }
+
+    Expression {
+        kind: Box::new(ExpressionKind::Unary(UnaryExpression {
+            operator: UnaryOperator::Negation("~"),
+            operand: atomic,
+            operator_span: span,
+        })),
+        span,
+    }
+}
+
+/// Joins `lhs` and `rhs` with `operator`, parenthesizing operands joined by
+/// another operator so the result prints the same with every precedence table.
+fn binary(lhs: Expression, operator: BinaryOperator, rhs: Expression, span: Span) -> Expression {
+    let paren = |expr: Expression| match expr.kind.as_ref() {
+        ExpressionKind::Binary(binary) if binary.operator != operator => Expression {
+            span: expr.span,
+            kind: Box::new(ExpressionKind::Paren(ParenExpression { expression: expr })),
+        },
+        _ => expr,
+    };
+
+    Expression {
+        kind: Box::new(ExpressionKind::Binary(BinaryExpression {
+            lhs: paren(lhs),
+            operator,
+            rhs: paren(rhs),
+            operator_span: span,
+        })),
+        span,
+    }
+}
+
+fn and(lhs: Expression, rhs: Expression, span: Span) -> Expression {
+    binary(lhs, BinaryOperator::Conjunction("&"), rhs, span)
+}
+
+fn or(lhs: Expression, rhs: Expression, span: Span) -> Expression {
+    binary(lhs, BinaryOperator::Disjunction("|"), rhs, span)
+}
+
+/// Returns `expr` in negation normal form if `positive` and its negation in
+/// negation normal form otherwise.
+fn nnf(expr: &Expression, positive: bool) -> Expression {
+    let span = expr.span;
+
+    match expr.kind.as_ref() {
+        ExpressionKind::TruthValue(truth_value) => {
+            self::truth_value(truth_value.value == positive, truth_value.span)
+        }
+        ExpressionKind::Atomic(atomic) => literal(&Literal {
+            atomic: atomic.clone(),
+            positive,
+        }),
+        ExpressionKind::Paren(paren) => nnf(&paren.expression, positive),
+        ExpressionKind::Unary(unary) => match unary.operator {
+            UnaryOperator::Negation(_) => nnf(&unary.operand, !positive),
+        },
+        ExpressionKind::Binary(binary) => {
+            let (lhs, rhs) = (&binary.lhs, &binary.rhs);
+
+            match (binary.operator, positive) {
+                (BinaryOperator::Conjunction(_), true) => and(nnf(lhs, true), nnf(rhs, true), span),
+                (BinaryOperator::Conjunction(_), false) => {
+                    or(nnf(lhs, false), nnf(rhs, false), span)
+                }
+                (BinaryOperator::Disjunction(_), true) => or(nnf(lhs, true), nnf(rhs, true), span),
+                (BinaryOperator::Disjunction(_), false) => {
+                    and(nnf(lhs, false), nnf(rhs, false), span)
+                }
+                (BinaryOperator::Implication(_), true) => or(nnf(lhs, false), nnf(rhs, true), span),
+                (BinaryOperator::Implication(_), false) => {
+                    and(nnf(lhs, true), nnf(rhs, false), span)
+                }
+                // a <-> b is (~a | b) & (a | ~b) and a ^ b is its negation
+                (BinaryOperator::Equivalence(_), true)
+                | (BinaryOperator::ExclusiveDisjunction(_), false) => and(
+                    or(nnf(lhs, false), nnf(rhs, true), span),
+                    or(nnf(lhs, true), nnf(rhs, false), span),
+                    span,
+                ),
+                (BinaryOperator::Equivalence(_), false)
+                | (BinaryOperator::ExclusiveDisjunction(_), true) => and(
+                    or(nnf(lhs, true), nnf(rhs, true), span),
+                    or(nnf(lhs, false), nnf(rhs, false), span),
+                    span,
+                ),
+            }
+        }
+    }
+}
+
+/// Returns the clauses of an expression in negation normal form, the
+/// clauses are joined by conjunctions and their literals by disjunctions if
+/// `conjunctive` and the other way around otherwise.
+///
+/// In conjunctive form `1` has no clauses and `0` has a single empty
+/// clause, in disjunctive form it is the opposite.
+fn clauses(expr: &Expression, conjunctive: bool) -> Vec<Vec<Literal>> {
+    match expr.kind.as_ref() {
+        ExpressionKind::TruthValue(truth_value) => {
+            if truth_value.value == conjunctive {
+                Vec::new()
+            } else {
+                vec![Vec::new()]
+            }
+        }
+        ExpressionKind::Atomic(atomic) => vec![vec![Literal {
+            atomic: atomic.clone(),
+            positive: true,
+        }]],
+        ExpressionKind::Paren(paren) => clauses(&paren.expression, conjunctive),
+        ExpressionKind::Unary(unary) => match unary.operand.kind.as_ref() {
+            ExpressionKind::Atomic(atomic) => vec![vec![Literal {
+                atomic: atomic.clone(),
+                positive: false,
+            }]],
+            _ => unreachable!("negations only apply to atomic propositions in NNF"),
+        },
+        ExpressionKind::Binary(binary) => {
+            let mut lhs = clauses(&binary.lhs, conjunctive);
+            let rhs = clauses(&binary.rhs, conjunctive);
+
+            let outer = match binary.operator {
+                BinaryOperator::Conjunction(_) => conjunctive,
+                BinaryOperator::Disjunction(_) => !conjunctive,
+                _ => unreachable!("only conjunctions and disjunctions remain in NNF"),
+            };
+
+            if outer {
+                lhs.extend(rhs);
+                return simplify(lhs);
+            }
+
+            // distribute every clause of one side over every clause of the other
+            let mut result = Vec::new();
+            for a in &lhs {
+                for b in &rhs {
+                    let mut clause = a.clone();
+                    clause.extend(b.iter().cloned());
+                    result.push(clause);
+                }
+            }
+
+            // simplifying every step keeps nested distributions small
+            simplify(result)
+        }
+    }
+}
+
+/// Removes repeated literals and clauses that contain a literal and its
+/// complement, as those never change the value of the normal form.
+fn simplify(clauses: Vec<Vec<Literal>>) -> Vec<Vec<Literal>> {
+    let mut result = Vec::new();
+
+    for clause in clauses {
+        let mut simplified: Vec<Literal> = Vec::new();
+        let mut trivial = false;
+
+        for literal in clause {
+            if simplified.iter().any(|other| other.is_complement(&literal)) {
+                trivial = true;
+                break;
+            }
+
+            if !simplified.iter().any(|other| other.is_same(&literal)) {
+                simplified.push(literal);
+            }
+        }
+
+        if !trivial {
+            result.push(simplified);
+        }
+    }
+
+    result
+}
+
+fn normal_form(expr: &Expression, conjunctive: bool) -> Expression {
+    let span = expr.span;
+    let clauses = clauses(&nnf(expr, true), conjunctive);
+
+    type Join = fn(Expression, Expression, Span) -> Expression;
+    let (outer, inner): (Join, Join) = if conjunctive { (and, or) } else { (or, and) };
+
+    clauses
+        .iter()
+        .map(|clause| {
+            clause
+                .iter()
+                .map(literal)
+                .reduce(|lhs, rhs| inner(lhs, rhs, span))
+                .unwrap_or_else(|| truth_value(!conjunctive, span))
+        })
+        .reduce(|lhs, rhs| outer(lhs, rhs, span))
+        .unwrap_or_else(|| truth_value(conjunctive, span))
+}
+
+/// Converts `expr` to negation normal form, where only conjunctions and
+/// disjunctions remain and every negation applies to an atomic proposition.
+///
+/// # Example
+/// ```rust
+/// # use dare::{to_nnf, Parser};
+/// let expr = Parser::new().parse("~(a -> ~b)").unwrap();
+///
+/// assert_eq!(to_nnf(&expr).to_string(), "a & b");
+/// ```
+pub fn to_nnf(expr: &Expression) -> Expression {
+    nnf(expr, true)
+}
+
+/// Converts `expr` to conjunctive normal form, a conjunction of disjunctions
+/// of literals.
+///
+/// # Example
+/// ```rust
+/// # use dare::{to_cnf, Parser};
+/// let expr = Parser::new().parse("a | (b & c)").unwrap();
+///
+/// assert_eq!(to_cnf(&expr).to_string(), "(a | b) & (a | c)");
+/// ```
+pub fn to_cnf(expr: &Expression) -> Expression {
+    normal_form(expr, true)
+}
+
+/// Converts `expr` to disjunctive normal form, a disjunction of conjunctions
+/// of literals.
+///
+/// # Example
+/// ```rust
+/// # use dare::{to_dnf, Parser};
+/// let expr = Parser::new().parse("a & (b -> c)").unwrap();
+///
+/// assert_eq!(to_dnf(&expr).to_string(), "(a & ~b) | (a & c)");
+/// ```
+pub fn to_dnf(expr: &Expression) -> Expression {
+    normal_form(expr, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_util::Rng, Parser, TruthTable};
+
+    use super::*;
+
+    /// Checks that `expr` only consists of `outer` joining `inner` joining literals.
+    fn is_normal(expr: &Expression, outer: &str, inner: &str) -> bool {
+        match expr.kind.as_ref() {
+            ExpressionKind::TruthValue(_) | ExpressionKind::Atomic(_) => true,
+            ExpressionKind::Paren(paren) => is_normal(&paren.expression, inner, inner),
+            ExpressionKind::Unary(unary) => {
+                matches!(unary.operand.kind.as_ref(), ExpressionKind::Atomic(_))
+            }
+            ExpressionKind::Binary(binary) => {
+                let operator = binary.operator.to_string();
+
+                if operator == outer {
+                    is_normal(&binary.lhs, outer, inner) && is_normal(&binary.rhs, outer, inner)
+                } else if operator == inner {
+                    is_normal(&binary.lhs, inner, inner) && is_normal(&binary.rhs, inner, inner)
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_normal_forms() {
+        let mut rng = Rng(0x5eed);
+
+        for _ in 0..200 {
+            let source = rng.source(4);
+            let expr = Parser::new().parse(&source).unwrap();
+
+            for (form, outer, inner) in [
+                (to_nnf(&expr), "", ""),
+                (to_cnf(&expr), "&", "|"),
+                (to_dnf(&expr), "|", "&"),
+            ] {
+                // comparing the printed form also checks that it reads back
+                // as the same expression
+                let equivalence = format!("({}) <-> ({})", source, form);
+                let table = TruthTable::new(&Parser::new().parse(&equivalence).unwrap()).unwrap();
+                assert!(
+                    table.rows().iter().all(|row| row.result()),
+                    "{} != {}",
+                    source,
+                    form
+                );
+
+                if !outer.is_empty() {
+                    assert!(is_normal(&form, outer, inner), "{} : {}", source, form);
+                }
+            }
+        }
+
+        let expr = Parser::new().parse("a & ~a").unwrap();
+        assert_eq!(to_cnf(&expr).to_string(), "a & ~a");
+        assert_eq!(to_dnf(&expr).to_string(), "0");
+
+        let expr = Parser::new().parse("a <-> b").unwrap();
+        assert_eq!(to_nnf(&expr).to_string(), "(~a | b) & (a | ~b)");
+        assert_eq!(to_dnf(&expr).to_string(), "(~a & ~b) | (b & a)");
+    }
+}
@@ -1,16 +1,69 @@
 use crate::{
-    AtomicExpression, BinaryExpression, Delimiter, Error, Expression, ExpressionKind,
-    ParenExpression, TokenKind, TokenStream, TruthValueExpression, UnaryExpression,
+    AtomicExpression, BinaryExpression, BinaryOperator, Definition, Delimiter, Entailment, Error,
+    ErrorKind, Expression, ExpressionKind, ParenExpression, Program, Span, Statement,
+    StatementKind, TokenKind, TokenStream, TruthValueExpression, UnaryExpression,
 };
 
+/// How chains of operators with equal precedence are parsed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Associativity {
+    /// Chains must be parenthesized unless every operator is the same
+    /// associative operator, e.g. `a & b & c`.
+    #[default]
+    Strict,
+    /// Chains are read from left to right, so `a -> b -> c` is `(a -> b) -> c`.
+    NonStrict,
+}
+
+/// The precedence table used to order binary operators.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Precedence {
+    /// Every binary operator has its own precedence, see
+    /// [`BinaryOperator::precedence`].
+    #[default]
+    Wikipedia,
+    /// Conjunction, disjunction and exclusive disjunction share the same
+    /// precedence.
+    Traditional,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ParserConfig {
+    pub associativity: Associativity,
+    pub precedence: Precedence,
+}
+
+impl ParserConfig {
+    /// Returns the precedence of `operator` in the configured table.
+    ///
+    /// **Note** that a lower number means a higher precedence.
+    pub const fn precedence(&self, operator: &BinaryOperator) -> i32 {
+        match (self.precedence, operator) {
+            (
+                Precedence::Traditional,
+                BinaryOperator::Conjunction(_)
+                | BinaryOperator::Disjunction(_)
+                | BinaryOperator::ExclusiveDisjunction(_),
+            ) => 1,
+            _ => operator.precedence(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
-pub struct Parser {}
+pub struct Parser {
+    pub config: ParserConfig,
+}
 
 impl Parser {
     pub fn new() -> Self {
         Self::default()
     }
 
+    pub fn with_config(config: ParserConfig) -> Self {
+        Self { config }
+    }
+
     pub fn parse_atomic_expr(&self, tokens: &mut TokenStream) -> Result<AtomicExpression, Error> {
         let next_token = tokens.next()?;
         match next_token.kind() {
@@ -18,7 +71,10 @@ impl Parser {
                 ident: identifier.clone(),
                 span: next_token.span().clone(),
             }),
-            _ => Err(Error::new().with_msg("expected atomic expression")),
+            _ => Err(Error::new()
+                .with_kind(ErrorKind::ExpectedExpression)
+                .with_msg("expected atomic expression")
+                .with_span(next_token.span())),
         }
     }
 
@@ -32,23 +88,95 @@ impl Parser {
                 value: value.clone(),
                 span: next_token.span().clone(),
             }),
-            _ => Err(Error::new().with_msg("expected truth value expression")),
+            _ => Err(Error::new()
+                .with_kind(ErrorKind::ExpectedExpression)
+                .with_msg("expected truth value expression")
+                .with_span(next_token.span())),
         }
     }
 
-    pub fn parse_paren_expr(&self, tokens: &mut TokenStream) -> Result<ParenExpression, Error> {
+    /// Parses a parenthesized expression.
+    ///
+    /// Errors inside the parentheses are pushed to `errors` and parsing
+    /// resumes after the matching closing parenthesis, with a placeholder in
+    /// place of the expression.
+    fn parse_paren_expr_recovering(
+        &self,
+        tokens: &mut TokenStream,
+        errors: &mut Vec<Error>,
+    ) -> Result<ParenExpression, Error> {
+        let start_span = tokens.span();
         tokens.expect(&TokenKind::Delimiter(Delimiter::Open))?;
-        let expression = self.parse_expression(tokens)?;
-        tokens.expect(&TokenKind::Delimiter(Delimiter::Close))?;
+
+        let result = self
+            .parse_expression_recovering(tokens, errors)
+            .and_then(|expression| {
+                tokens.expect(&TokenKind::Delimiter(Delimiter::Close))?;
+                Ok(expression)
+            });
+
+        let expression = match result {
+            Ok(expression) => expression,
+            Err(error) => {
+                errors.push(error);
+                Self::synchronize_paren(tokens);
+
+                // the expression is discarded since an error was recorded
+                Expression {
+                    kind: Box::new(ExpressionKind::TruthValue(TruthValueExpression {
+                        value: false,
+                        span: start_span,
+                    })),
+                    span: start_span + tokens.last_span(),
+                }
+            }
+        };
+
         Ok(ParenExpression { expression })
     }
 
-    pub fn parse_term_expr(&self, tokens: &mut TokenStream) -> Result<Expression, Error> {
+    /// Skips tokens until the closing parenthesis of the current group has been
+    /// consumed, or until the end of the current statement.
+    fn synchronize_paren(tokens: &mut TokenStream) {
+        let mut depth = 0;
+
+        while let Some(kind) = tokens.try_peek_kind() {
+            match kind {
+                TokenKind::Delimiter(Delimiter::Separator) => return,
+                TokenKind::Delimiter(Delimiter::Open) => depth += 1,
+                TokenKind::Delimiter(Delimiter::Close) if depth == 0 => {
+                    tokens.try_next();
+                    return;
+                }
+                TokenKind::Delimiter(Delimiter::Close) => depth -= 1,
+                _ => {}
+            }
+
+            tokens.try_next();
+        }
+    }
+
+    /// Skips tokens until the start of the next statement.
+    fn synchronize_statement(tokens: &mut TokenStream) {
+        while let Some(kind) = tokens.try_peek_kind() {
+            if *kind == TokenKind::Delimiter(Delimiter::Separator) || tokens.is_line_break() {
+                return;
+            }
+
+            tokens.try_next();
+        }
+    }
+
+    fn parse_term_expr_recovering(
+        &self,
+        tokens: &mut TokenStream,
+        errors: &mut Vec<Error>,
+    ) -> Result<Expression, Error> {
         match tokens.peek()?.kind() {
             TokenKind::Delimiter(Delimiter::Open) => {
                 let start_span = tokens.span();
-                let expression = self.parse_paren_expr(tokens)?;
-                let end_span = tokens.span();
+                let expression = self.parse_paren_expr_recovering(tokens, errors)?;
+                let end_span = tokens.last_span();
                 Ok(Expression {
                     kind: Box::new(ExpressionKind::Paren(expression)),
                     span: start_span + end_span,
@@ -57,7 +185,7 @@ impl Parser {
             TokenKind::Identifier(_) => {
                 let start_span = tokens.span();
                 let expression = self.parse_atomic_expr(tokens)?;
-                let end_span = tokens.span();
+                let end_span = tokens.last_span();
                 Ok(Expression {
                     kind: Box::new(ExpressionKind::Atomic(expression)),
                     span: start_span + end_span,
@@ -66,7 +194,7 @@ impl Parser {
             TokenKind::TruthValue(_) => {
                 let start_span = tokens.span();
                 let expression = self.parse_truthvalue_expr(tokens)?;
-                let end_span = tokens.span();
+                let end_span = tokens.last_span();
                 Ok(Expression {
                     kind: Box::new(ExpressionKind::TruthValue(expression)),
                     span: start_span + end_span,
@@ -74,6 +202,7 @@ impl Parser {
             }
             _ => {
                 let error = Error::new()
+                    .with_kind(ErrorKind::ExpectedExpression)
                     .with_msg("expected expression")
                     .with_span(tokens.span());
                 Err(error)
@@ -81,15 +210,19 @@ impl Parser {
         }
     }
 
-    pub fn parse_unary_expr(&self, tokens: &mut TokenStream) -> Result<Expression, Error> {
+    fn parse_unary_expr_recovering(
+        &self,
+        tokens: &mut TokenStream,
+        errors: &mut Vec<Error>,
+    ) -> Result<Expression, Error> {
         let start_span = tokens.span();
 
         match tokens.try_peek_kind() {
             Some(TokenKind::UnaryOperator(operator)) => {
                 let operator = operator.clone();
                 let operator_span = tokens.next()?.span();
-                let operand = self.parse_unary_expr(tokens)?;
-                let end_span = tokens.span();
+                let operand = self.parse_unary_expr_recovering(tokens, errors)?;
+                let end_span = tokens.last_span();
                 Ok(Expression {
                     kind: Box::new(ExpressionKind::Unary(UnaryExpression {
                         operator,
@@ -99,78 +232,424 @@ impl Parser {
                     span: start_span + end_span,
                 })
             }
-            _ => self.parse_term_expr(tokens),
+            _ => self.parse_term_expr_recovering(tokens, errors),
         }
     }
 
-    pub fn parse_expression(&self, tokens: &mut TokenStream) -> Result<Expression, Error> {
+    /// Parses a chain of binary expressions by precedence climbing.
+    ///
+    /// Only operators with a precedence number of at most `max_precedence`
+    /// are consumed, every right operand is parsed with a strictly tighter
+    /// bound so operators of equal precedence are read from left to right.
+    fn parse_binary_expr(
+        &self,
+        tokens: &mut TokenStream,
+        max_precedence: i32,
+        errors: &mut Vec<Error>,
+    ) -> Result<Expression, Error> {
+        let mut lhs = self.parse_unary_expr_recovering(tokens, errors)?;
+        let mut previous: Option<(BinaryOperator, Span)> = None;
+
+        while let Some(TokenKind::BinaryOperator(operator)) = tokens.try_peek_kind() {
+            let operator = *operator;
+            let precedence = self.config.precedence(&operator);
+
+            if precedence > max_precedence {
+                break;
+            }
+
+            let operator_span = tokens.next()?.span();
+            let rhs = self.parse_binary_expr(tokens, precedence - 1, errors)?;
+            let span = lhs.span + rhs.span;
+
+            if let Some((previous_operator, previous_span)) = previous {
+                if self.config.associativity == Associativity::Strict
+                    && self.config.precedence(&previous_operator) == precedence
+                    && !operator.associates_with(&previous_operator)
+                {
+                    let error = Error::new()
+                        .with_kind(ErrorKind::NonAssociative)
+                        .with_msg("non-associative operators must be parenthesized")
+                        .with_span(span)
+                        .with_msg(format!(
+                            "'{}' is chained with this '{}'",
+                            operator, previous_operator
+                        ))
+                        .with_span(previous_span)
+                        .with_help("add parentheses, e.g. `(a -> b) -> c`");
+                    return Err(error);
+                }
+            }
+
+            lhs = Expression {
+                kind: Box::new(ExpressionKind::Binary(BinaryExpression {
+                    lhs,
+                    operator,
+                    rhs,
+                    operator_span,
+                })),
+                span,
+            };
+            previous = Some((operator, operator_span));
+        }
+
+        Ok(lhs)
+    }
+
+    fn parse_expression_recovering(
+        &self,
+        tokens: &mut TokenStream,
+        errors: &mut Vec<Error>,
+    ) -> Result<Expression, Error> {
+        self.parse_binary_expr(tokens, i32::MAX, errors)
+    }
+
+    fn parse_definition_recovering(
+        &self,
+        tokens: &mut TokenStream,
+        errors: &mut Vec<Error>,
+    ) -> Result<Definition, Error> {
         let start_span = tokens.span();
-        let lhs = self.parse_unary_expr(tokens)?;
+        let name = self.parse_atomic_expr(tokens)?;
 
-        match tokens.try_peek_kind() {
-            Some(TokenKind::BinaryOperator(operator)) => {
-                let operator = operator.clone();
-                let operator_span = tokens.next()?.span();
-                let rhs = self.parse_expression(tokens)?;
-                let end_span = tokens.span();
-
-                if let ExpressionKind::Binary(rhs_expr) = rhs.kind.as_ref() {
-                    if operator.precedence() == rhs_expr.operator.precedence() {
-                        if !operator.is_associative() || !rhs_expr.operator.is_associative() {
-                            let error = Error::new()
-                                .with_msg("non-associative operators must be parenthesized")
-                                .with_span(start_span + end_span);
-                            return Err(error);
-                        }
-                    }
+        let assignment = tokens.next()?;
+        let operator = match assignment.kind() {
+            TokenKind::Assignment(operator) => *operator,
+            _ => {
+                let error = Error::new()
+                    .with_kind(ErrorKind::ExpectedToken)
+                    .with_msg("expected assignment")
+                    .with_span(assignment.span());
+                return Err(error);
+            }
+        };
+        let operator_span = assignment.span();
+
+        let expression = self.parse_expression_recovering(tokens, errors)?;
+
+        Ok(Definition {
+            span: start_span + expression.span,
+            name,
+            operator,
+            expression,
+            operator_span,
+        })
+    }
+
+    fn parse_entailment_recovering(
+        &self,
+        tokens: &mut TokenStream,
+        mut premises: Vec<Expression>,
+        errors: &mut Vec<Error>,
+    ) -> Result<Entailment, Error> {
+        while let Some(TokenKind::Delimiter(Delimiter::Comma)) = tokens.try_peek_kind() {
+            tokens.next()?;
+            premises.push(self.parse_expression_recovering(tokens, errors)?);
+        }
+
+        let token = tokens.next()?;
+        let operator = match token.kind() {
+            TokenKind::Entailment(operator) => *operator,
+            _ => {
+                let error = Error::new()
+                    .with_kind(ErrorKind::ExpectedToken)
+                    .with_msg("expected entailment")
+                    .with_span(token.span());
+                return Err(error);
+            }
+        };
+        let operator_span = token.span();
+
+        let conclusion = self.parse_expression_recovering(tokens, errors)?;
+
+        Ok(Entailment {
+            premises,
+            operator,
+            conclusion,
+            operator_span,
+        })
+    }
+
+    fn parse_statement_recovering(
+        &self,
+        tokens: &mut TokenStream,
+        errors: &mut Vec<Error>,
+    ) -> Result<Statement, Error> {
+        let start_span = tokens.span();
+
+        let kind = match (tokens.try_peek_kind(), tokens.try_peek_nth_kind(1)) {
+            (Some(TokenKind::Identifier(_)), Some(TokenKind::Assignment(_))) => {
+                StatementKind::Definition(self.parse_definition_recovering(tokens, errors)?)
+            }
+            (Some(TokenKind::Entailment(_)), _) => StatementKind::Entailment(
+                self.parse_entailment_recovering(tokens, Vec::new(), errors)?,
+            ),
+            _ => {
+                let expression = self.parse_expression_recovering(tokens, errors)?;
 
-                    if operator.precedence() < rhs_expr.operator.precedence() {
-                        let expression = Expression {
-                            kind: Box::new(ExpressionKind::Binary(BinaryExpression {
-                                lhs,
-                                operator,
-                                rhs: rhs_expr.lhs.clone(),
-                                operator_span,
-                            })),
-                            span: rhs.span,
-                        };
-
-                        return Ok(Expression {
-                            kind: Box::new(ExpressionKind::Binary(BinaryExpression {
-                                lhs: expression,
-                                operator: rhs_expr.operator.clone(),
-                                rhs: rhs_expr.rhs.clone(),
-                                operator_span: rhs_expr.operator_span,
-                            })),
-                            span: start_span + end_span,
-                        });
+                match tokens.try_peek_kind() {
+                    Some(TokenKind::Delimiter(Delimiter::Comma) | TokenKind::Entailment(_)) => {
+                        let entailment =
+                            self.parse_entailment_recovering(tokens, vec![expression], errors)?;
+                        StatementKind::Entailment(entailment)
                     }
+                    _ => StatementKind::Expression(expression),
                 }
+            }
+        };
 
-                Ok(Expression {
-                    kind: Box::new(ExpressionKind::Binary(BinaryExpression {
-                        lhs,
-                        operator,
-                        rhs,
-                        operator_span,
-                    })),
-                    span: start_span + end_span,
-                })
+        Ok(Statement {
+            kind,
+            span: start_span + tokens.last_span(),
+        })
+    }
+
+    /// Runs `parse` and returns the first error it pushed to its sink, so the
+    /// placeholders of recovered errors never reach the caller.
+    fn without_recovery<T>(
+        parse: impl FnOnce(&mut Vec<Error>) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let mut errors = Vec::new();
+        let result = parse(&mut errors)?;
+
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(result),
+        }
+    }
+
+    pub fn parse_paren_expr(&self, tokens: &mut TokenStream) -> Result<ParenExpression, Error> {
+        Self::without_recovery(|errors| self.parse_paren_expr_recovering(tokens, errors))
+    }
+
+    pub fn parse_term_expr(&self, tokens: &mut TokenStream) -> Result<Expression, Error> {
+        Self::without_recovery(|errors| self.parse_term_expr_recovering(tokens, errors))
+    }
+
+    pub fn parse_unary_expr(&self, tokens: &mut TokenStream) -> Result<Expression, Error> {
+        Self::without_recovery(|errors| self.parse_unary_expr_recovering(tokens, errors))
+    }
+
+    pub fn parse_expression(&self, tokens: &mut TokenStream) -> Result<Expression, Error> {
+        Self::without_recovery(|errors| self.parse_expression_recovering(tokens, errors))
+    }
+
+    pub fn parse_definition(&self, tokens: &mut TokenStream) -> Result<Definition, Error> {
+        Self::without_recovery(|errors| self.parse_definition_recovering(tokens, errors))
+    }
+
+    /// Parses the rest of an [`Entailment`] whose premises have already been parsed.
+    pub fn parse_entailment(
+        &self,
+        tokens: &mut TokenStream,
+        premises: Vec<Expression>,
+    ) -> Result<Entailment, Error> {
+        Self::without_recovery(|errors| self.parse_entailment_recovering(tokens, premises, errors))
+    }
+
+    pub fn parse_statement(&self, tokens: &mut TokenStream) -> Result<Statement, Error> {
+        Self::without_recovery(|errors| self.parse_statement_recovering(tokens, errors))
+    }
+
+    /// Tries to parse `source` as a [`Program`] of [`Statement`]s separated by
+    /// `;` or line breaks, returning the first error.
+    pub fn parse_program(&self, source: impl AsRef<str>) -> Result<Program, Error> {
+        let mut errors = Vec::new();
+        let program = self.parse_program_with_errors(source, &mut errors);
+
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(program),
+        }
+    }
+
+    /// Parses `source` as a [`Program`], pushing an [`Error`] to `errors` for
+    /// every invalid statement and continuing with the next one.
+    ///
+    /// The program only contains the statements without errors.
+    pub fn parse_program_with_errors(
+        &self,
+        source: impl AsRef<str>,
+        errors: &mut Vec<Error>,
+    ) -> Program {
+        let mut tokens = TokenStream::parse_with_errors(source.as_ref(), errors);
+        let mut program = Program::default();
+
+        loop {
+            while let Some(TokenKind::Delimiter(Delimiter::Separator)) = tokens.try_peek_kind() {
+                tokens.try_next();
+            }
+
+            if tokens.is_empty() {
+                break;
+            }
+
+            let error_count = errors.len();
+
+            match self.parse_statement_recovering(&mut tokens, errors) {
+                // statements with recovered errors contain placeholders
+                Ok(_) if errors.len() > error_count => {}
+                Ok(statement) => program.statements.push(statement),
+                Err(error) => {
+                    errors.push(error);
+                    Self::synchronize_statement(&mut tokens);
+                    continue;
+                }
+            }
+
+            match tokens.try_peek_kind() {
+                None | Some(TokenKind::Delimiter(Delimiter::Separator)) => {}
+                Some(_) if tokens.is_line_break() => {}
+                Some(_) => {
+                    let error = Error::new()
+                        .with_kind(ErrorKind::ExpectedToken)
+                        .with_msg("expected ';' or a line break")
+                        .with_span(tokens.span())
+                        .with_help("statements are separated by ';' or line breaks");
+                    errors.push(error);
+                    Self::synchronize_statement(&mut tokens);
+                }
             }
-            _ => Ok(lhs),
         }
+
+        program
     }
 
+    /// Tries to parse `source` as a single [`Expression`], returning the first
+    /// error.
     pub fn parse(&self, source: impl AsRef<str>) -> Result<Expression, Error> {
-        let mut tokens = TokenStream::parse(source.as_ref())?;
-        self.parse_expression(&mut tokens)
+        let mut errors = Vec::new();
+
+        match self.parse_with_errors(source, &mut errors) {
+            Some(expression) => Ok(expression),
+            None => Err(errors.into_iter().next().unwrap()),
+        }
+    }
+
+    /// Parses `source` as a single [`Expression`], pushing an [`Error`] to
+    /// `errors` for every invalid part of it.
+    ///
+    /// Returns `None` if there was any error.
+    pub fn parse_with_errors(
+        &self,
+        source: impl AsRef<str>,
+        errors: &mut Vec<Error>,
+    ) -> Option<Expression> {
+        let error_count = errors.len();
+        let mut tokens = TokenStream::parse_with_errors(source.as_ref(), errors);
+
+        match self.parse_expression_recovering(&mut tokens, errors) {
+            Ok(_) if !tokens.is_empty() => {
+                let error = Error::new()
+                    .with_kind(ErrorKind::UnexpectedToken)
+                    .with_msg("unexpected token after expression")
+                    .with_span(tokens.span() + tokens.eof_span());
+                errors.push(error);
+            }
+            Ok(expression) if errors.len() == error_count => return Some(expression),
+            Ok(_) => {}
+            Err(error) => errors.push(error),
+        }
+
+        None
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::test_util::Rng;
+
     use super::*;
 
+    /// Prints `expr` with every binary expression parenthesized.
+    fn structure(expr: &Expression) -> String {
+        match expr.kind.as_ref() {
+            ExpressionKind::TruthValue(truth_value) => truth_value.to_string(),
+            ExpressionKind::Atomic(atomic) => atomic.to_string(),
+            ExpressionKind::Paren(paren) => structure(&paren.expression),
+            ExpressionKind::Unary(unary) => {
+                format!("{}{}", unary.operator, structure(&unary.operand))
+            }
+            ExpressionKind::Binary(binary) => format!(
+                "({} {} {})",
+                structure(&binary.lhs),
+                binary.operator,
+                structure(&binary.rhs)
+            ),
+        }
+    }
+
+    /// A randomly generated expression, printed both with the fewest
+    /// parentheses `config` needs and in the form of [`structure`].
+    struct Sample {
+        source: String,
+        structure: String,
+        operator: Option<BinaryOperator>,
+    }
+
+    fn sample(rng: &mut Rng, config: &ParserConfig, depth: u32) -> Sample {
+        const OPERATORS: [BinaryOperator; 5] = [
+            BinaryOperator::Conjunction("&"),
+            BinaryOperator::Disjunction("|"),
+            BinaryOperator::ExclusiveDisjunction("^"),
+            BinaryOperator::Implication("->"),
+            BinaryOperator::Equivalence("<->"),
+        ];
+
+        match if depth == 0 { 0 } else { rng.below(4) } {
+            0 => {
+                let name = ["a", "b", "c", "d"][rng.below(4) as usize];
+                Sample {
+                    source: String::from(name),
+                    structure: String::from(name),
+                    operator: None,
+                }
+            }
+            1 => {
+                let operand = sample(rng, config, depth - 1);
+                let source = match operand.operator {
+                    Some(_) => format!("~({})", operand.source),
+                    None => format!("~{}", operand.source),
+                };
+                Sample {
+                    source,
+                    structure: format!("~{}", operand.structure),
+                    operator: None,
+                }
+            }
+            _ => {
+                let operator = OPERATORS[rng.below(5) as usize];
+                let precedence = config.precedence(&operator);
+                let lhs = sample(rng, config, depth - 1);
+                let rhs = sample(rng, config, depth - 1);
+
+                let lhs_source = match lhs.operator {
+                    Some(lhs_operator)
+                        if config.precedence(&lhs_operator) > precedence
+                            || config.precedence(&lhs_operator) == precedence
+                                && config.associativity == Associativity::Strict
+                                && !operator.associates_with(&lhs_operator) =>
+                    {
+                        format!("({})", lhs.source)
+                    }
+                    _ => lhs.source,
+                };
+                let rhs_source = match rhs.operator {
+                    Some(rhs_operator) if config.precedence(&rhs_operator) >= precedence => {
+                        format!("({})", rhs.source)
+                    }
+                    _ => rhs.source,
+                };
+
+                Sample {
+                    source: format!("{} {} {}", lhs_source, operator, rhs_source),
+                    structure: format!("({} {} {})", lhs.structure, operator, rhs.structure),
+                    operator: Some(operator),
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_parser() {
         let parser = Parser::new();
@@ -198,4 +677,246 @@ mod tests {
             "(A -> B)",
         }
     }
+
+    #[test]
+    fn test_parse_definition() {
+        let parser = Parser::new();
+
+        for source in ["p := q & r", "p : ~q"] {
+            let mut tokens = TokenStream::parse(source).unwrap();
+            let definition = parser.parse_definition(&mut tokens).unwrap();
+            assert_eq!(definition.to_string(), source);
+            assert_eq!(definition.span, Span::new(0, source.len()));
+        }
+
+        // the span ends with the definition, not at the next token
+        let mut tokens = TokenStream::parse("p := q\nr").unwrap();
+        let definition = parser.parse_definition(&mut tokens).unwrap();
+        assert_eq!(definition.span, Span::new(0, 6));
+
+        let mut tokens = TokenStream::parse("p & q").unwrap();
+        assert!(parser.parse_definition(&mut tokens).is_err());
+    }
+
+    #[test]
+    fn test_parse_program() {
+        let parser = Parser::new();
+
+        let source = "p := q & r\np | s; ~p\n\n(a ->\n b), a ⊨ b;;\n|= a | ~a";
+        let program = parser.parse_program(source).unwrap();
+
+        assert_eq!(
+            program.to_string(),
+            "p := q & r\np | s\n~p\n(a -> b), a ⊨ b\n|= a | ~a\n"
+        );
+
+        let spans = program
+            .statements
+            .iter()
+            .map(|statement| &source[statement.span.range()])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            spans,
+            [
+                "p := q & r",
+                "p | s",
+                "~p",
+                "(a ->\n b), a ⊨ b",
+                "|= a | ~a"
+            ]
+        );
+
+        assert!(matches!(
+            program.statements[0].kind,
+            StatementKind::Definition(_)
+        ));
+        assert!(matches!(
+            program.statements[1].kind,
+            StatementKind::Expression(_)
+        ));
+        match &program.statements[3].kind {
+            StatementKind::Entailment(entailment) => assert_eq!(entailment.premises.len(), 2),
+            _ => panic!("expected entailment"),
+        }
+
+        let program = parser.parse_program("p -> q, p ⊢ q\n|- p | ~p").unwrap();
+        assert_eq!(program.to_string(), "p -> q, p ⊢ q\n|- p | ~p\n");
+
+        assert!(parser.parse_program("a b").is_err());
+        assert!(parser.parse_program("a, b").is_err());
+        assert!(parser.parse_program("").unwrap().statements.is_empty());
+    }
+
+    #[test]
+    fn test_parser_errors() {
+        let parser = Parser::new();
+
+        let error = parser.parse("a b").unwrap_err();
+        assert_eq!(error.messages()[0], "unexpected token after expression");
+        assert_eq!(error.kind(), ErrorKind::UnexpectedToken);
+        assert_eq!(error.spans()[0], Span::new(2, 1));
+
+        assert!(parser.parse("a)").is_err());
+        assert!(parser.parse("(a").is_err());
+
+        let mut errors = Vec::new();
+        assert!(parser
+            .parse_with_errors("(a &) | (~) & $ c", &mut errors)
+            .is_none());
+        assert_eq!(errors[0].messages()[0], "unexpected symbol '$'");
+        assert_eq!(errors[0].kind(), ErrorKind::UnexpectedSymbol);
+
+        let spans = errors
+            .iter()
+            .map(|error| error.spans()[0])
+            .collect::<Vec<_>>();
+        assert_eq!(spans, [Span::new(14, 1), Span::new(4, 1), Span::new(10, 1)]);
+
+        let source = "p := ; a &\nb c; (d e) f\n g";
+        assert!(parser.parse_program(source).is_err());
+
+        let mut errors = Vec::new();
+        let program = parser.parse_program_with_errors(source, &mut errors);
+        assert_eq!(program.to_string(), "a & b\ng\n");
+
+        let messages = errors
+            .iter()
+            .map(|error| error.messages()[0].as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "expected expression",
+                "expected ';' or a line break",
+                "expected symbol 'Delimiter(Close)'",
+                "expected ';' or a line break",
+            ]
+        );
+
+        // errors don't carry over to the next call
+        assert!(parser.parse_program("a & b").is_ok());
+
+        // only programs recover, the other entry points never return the
+        // placeholder of a recovered error
+        let mut tokens = TokenStream::parse("(a &) | b").unwrap();
+        let error = parser.parse_expression(&mut tokens).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::ExpectedExpression);
+
+        let mut tokens = TokenStream::parse("p := ~(q r)").unwrap();
+        assert!(parser.parse_definition(&mut tokens).is_err());
+
+        let mut tokens = TokenStream::parse("(a b)").unwrap();
+        assert!(parser.parse_term_expr(&mut tokens).is_err());
+    }
+
+    #[test]
+    fn test_parser_config() {
+        macro_rules! parser_tests {
+            ($config:expr, $($source:literal => $expected:literal),* $(,)?) => {$({
+                let parser = Parser::with_config($config);
+                let expr = parser.parse($source).unwrap();
+                assert_eq!(structure(&expr), $expected, "{}", $source);
+            })*};
+        }
+
+        macro_rules! parser_errors {
+            ($config:expr, $($source:literal),* $(,)?) => {$({
+                let parser = Parser::with_config($config);
+                let error = parser.parse($source).unwrap_err();
+                assert_eq!(error.kind(), ErrorKind::NonAssociative, "{}", $source);
+            })*};
+        }
+
+        let strict = ParserConfig::default();
+        let non_strict = ParserConfig {
+            associativity: Associativity::NonStrict,
+            ..Default::default()
+        };
+        let traditional = ParserConfig {
+            precedence: Precedence::Traditional,
+            ..Default::default()
+        };
+        let traditional_non_strict = ParserConfig {
+            associativity: Associativity::NonStrict,
+            precedence: Precedence::Traditional,
+        };
+
+        parser_tests! {
+            strict,
+            "a & b & c" => "((a & b) & c)",
+            "~a | b & c" => "(~a | (b & c))",
+            "a | b & c -> d & e" => "((a | (b & c)) -> (d & e))",
+            "a -> b & c | d" => "(a -> ((b & c) | d))",
+            "(a -> b) -> c" => "((a -> b) -> c)",
+        }
+
+        parser_errors! {
+            strict,
+            "a -> b -> c",
+            "a <-> b <-> c",
+            "a -> b & c -> d",
+        }
+
+        parser_tests! {
+            non_strict,
+            "a -> b -> c" => "((a -> b) -> c)",
+            "a -> b -> c -> d" => "(((a -> b) -> c) -> d)",
+            "a <-> b <-> c" => "((a <-> b) <-> c)",
+            "a -> b & c -> d" => "((a -> (b & c)) -> d)",
+        }
+
+        parser_tests! {
+            traditional_non_strict,
+            "~a | b & c" => "((~a | b) & c)",
+            "a & b ^ c | d -> e" => "((((a & b) ^ c) | d) -> e)",
+        }
+
+        parser_tests! {
+            traditional,
+            "a & b & c" => "((a & b) & c)",
+        }
+
+        parser_errors! {
+            traditional,
+            "~a | b & c",
+            "a ^ b ^ c & d",
+        }
+    }
+
+    #[test]
+    fn test_parser_precedence_climbing() {
+        let configs = [
+            ParserConfig::default(),
+            ParserConfig {
+                associativity: Associativity::NonStrict,
+                ..Default::default()
+            },
+            ParserConfig {
+                precedence: Precedence::Traditional,
+                ..Default::default()
+            },
+            ParserConfig {
+                associativity: Associativity::NonStrict,
+                precedence: Precedence::Traditional,
+            },
+        ];
+
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+        for config in configs {
+            for _ in 0..500 {
+                let sample = sample(&mut rng, &config, 5);
+                let parser = Parser::with_config(config);
+
+                let expr = parser
+                    .parse(&sample.source)
+                    .unwrap_or_else(|error| panic!("{}: {}", sample.source, error));
+                assert_eq!(structure(&expr), sample.structure, "{:?}", config);
+
+                // the fully parenthesized form parses to the same tree
+                let expr = parser.parse(&sample.structure).unwrap();
+                assert_eq!(structure(&expr), sample.structure, "{:?}", config);
+            }
+        }
+    }
 }
@@ -0,0 +1,893 @@
+use crate::Cnf;
+
+/// Returns the value of `literal` under `values`, `None` if its variable is
+/// unassigned.
+///
+/// Literals are numbered `2 * variable + negated` with variables counted
+/// from 0, so a literal and its complement only differ in the lowest bit.
+fn value(values: &[Option<bool>], literal: usize) -> Option<bool> {
+    values[literal >> 1].map(|value| value != (literal & 1 == 1))
+}
+
+/// The number of conflicts between restarts is this times a term of the
+/// Luby sequence.
+const RESTART_INTERVAL: u64 = 100;
+
+/// Returns term `index` of the Luby sequence 1, 1, 2, 1, 1, 2, 4, 1, …
+fn luby(mut index: u32) -> u64 {
+    // the sequence is made of blocks of size 2^k - 1 ending in 2^(k - 1)
+    let mut size = 1;
+    let mut exponent = 0;
+    while size < index + 1 {
+        exponent += 1;
+        size = 2 * size + 1;
+    }
+
+    while size - 1 != index {
+        size = (size - 1) >> 1;
+        exponent -= 1;
+        index %= size;
+    }
+
+    1 << exponent
+}
+
+/// A binary max-heap of variables ordered by their activity, which keeps the
+/// position of every variable so a bumped variable can be moved up.
+#[derive(Clone, Debug, Default)]
+struct VariableHeap {
+    heap: Vec<usize>,
+    /// The index of every variable in `heap`, `None` if it isn't in it.
+    positions: Vec<Option<usize>>,
+}
+
+impl VariableHeap {
+    fn new(count: usize) -> Self {
+        Self {
+            heap: (0..count).collect(),
+            positions: (0..count).map(Some).collect(),
+        }
+    }
+
+    fn push(&mut self, variable: usize, activity: &[f64]) {
+        if self.positions[variable].is_some() {
+            return;
+        }
+
+        self.positions[variable] = Some(self.heap.len());
+        self.heap.push(variable);
+        self.sift_up(self.heap.len() - 1, activity);
+    }
+
+    fn pop(&mut self, activity: &[f64]) -> Option<usize> {
+        let top = *self.heap.first()?;
+        let last = self.heap.pop().unwrap();
+        self.positions[top] = None;
+
+        if !self.heap.is_empty() {
+            self.heap[0] = last;
+            self.positions[last] = Some(0);
+            self.sift_down(0, activity);
+        }
+
+        Some(top)
+    }
+
+    /// Restores the order after the activity of `variable` increased.
+    fn bumped(&mut self, variable: usize, activity: &[f64]) {
+        if let Some(index) = self.positions[variable] {
+            self.sift_up(index, activity);
+        }
+    }
+
+    fn sift_up(&mut self, mut index: usize, activity: &[f64]) {
+        let variable = self.heap[index];
+
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if activity[self.heap[parent]] >= activity[variable] {
+                break;
+            }
+
+            self.heap[index] = self.heap[parent];
+            self.positions[self.heap[index]] = Some(index);
+            index = parent;
+        }
+
+        self.heap[index] = variable;
+        self.positions[variable] = Some(index);
+    }
+
+    fn sift_down(&mut self, mut index: usize, activity: &[f64]) {
+        let variable = self.heap[index];
+
+        loop {
+            let left = 2 * index + 1;
+            if left >= self.heap.len() {
+                break;
+            }
+
+            let right = left + 1;
+            let child = if right < self.heap.len()
+                && activity[self.heap[right]] > activity[self.heap[left]]
+            {
+                right
+            } else {
+                left
+            };
+
+            if activity[self.heap[child]] <= activity[variable] {
+                break;
+            }
+
+            self.heap[index] = self.heap[child];
+            self.positions[self.heap[index]] = Some(index);
+            index = child;
+        }
+
+        self.heap[index] = variable;
+        self.positions[variable] = Some(index);
+    }
+}
+
+/// A conflict driven clause learning (CDCL) SAT solver for [`Cnf`]s.
+///
+/// Clauses are watched by two of their literals, so only clauses watching a
+/// literal that became false are visited during unit propagation. Every
+/// conflict is analyzed up to its first unique implication point, the
+/// learnt clause is added and the solver jumps back to the second highest
+/// decision level of the clause.
+///
+/// Decisions pick the unassigned variable that took part in the most recent
+/// conflicts and give it the value it had last, initially `true` only if
+/// its negation occurs in no clause. The search restarts after a number of
+/// conflicts following the Luby sequence, and on restarts half of the
+/// learnt clauses spanning many decision levels are deleted.
+///
+/// Models leave out the values no clause needs, so variables that don't
+/// matter stay unassigned. Of the true literals of a clause, those already
+/// kept are preferred, then those of auxiliary variables.
+///
+/// # Example
+/// ```rust
+/// # use dare::{tseitin, Parser, SatSolver};
+/// let expr = Parser::new().parse("(a | b) & ~a").unwrap();
+/// let cnf = tseitin(&expr);
+/// let mut solver = SatSolver::new(&cnf);
+///
+/// let model = solver.solve().unwrap();
+/// assert_eq!(model[cnf.variable("a").unwrap() as usize - 1], Some(false));
+/// assert_eq!(model[cnf.variable("b").unwrap() as usize - 1], Some(true));
+///
+/// // forbid the only model
+/// solver.add_clause(&[-(cnf.variable("b").unwrap() as i32)]);
+/// assert_eq!(solver.solve(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SatSolver {
+    clauses: Vec<Vec<usize>>,
+    /// The literal block distance of every learnt clause, the number of
+    /// decision levels it spanned when it was learnt, `None` for the
+    /// clauses that were added.
+    distances: Vec<Option<usize>>,
+    learnt_count: usize,
+    max_learnt_count: usize,
+    /// The clauses watched by every literal, each with another of its
+    /// literals that satisfies it if true. The watched literals of a clause
+    /// are its first two.
+    watches: Vec<Vec<(usize, usize)>>,
+    /// Whether every literal occurs in an added clause.
+    occurs: Vec<bool>,
+    values: Vec<Option<bool>>,
+    /// The value of every variable before it was last unassigned.
+    phases: Vec<Option<bool>>,
+    levels: Vec<usize>,
+    /// The clause that implied the value of every variable, `None` for
+    /// decisions and values fixed before any decision.
+    reasons: Vec<Option<usize>>,
+    /// The assigned literals in the order they were assigned.
+    trail: Vec<usize>,
+    /// The length of the trail before every decision.
+    trail_limits: Vec<usize>,
+    /// The index of the next trail literal to propagate.
+    head: usize,
+    activity: Vec<f64>,
+    increment: f64,
+    /// The unassigned variables and possibly some assigned ones, by activity.
+    heap: VariableHeap,
+    /// The variables met during conflict analysis, all `false` in between.
+    seen: Vec<bool>,
+    restarts: u32,
+    /// Whether every variable is the atomic proposition of a name.
+    named: Vec<bool>,
+    unsatisfiable: bool,
+}
+
+impl SatSolver {
+    pub fn new(cnf: &Cnf) -> Self {
+        let count = cnf.variable_count();
+        let mut this = Self {
+            clauses: Vec::new(),
+            distances: Vec::new(),
+            learnt_count: 0,
+            max_learnt_count: 0,
+            watches: vec![Vec::new(); 2 * count],
+            occurs: vec![false; 2 * count],
+            values: vec![None; count],
+            phases: vec![None; count],
+            levels: vec![0; count],
+            reasons: vec![None; count],
+            trail: Vec::new(),
+            trail_limits: Vec::new(),
+            head: 0,
+            activity: vec![0.0; count],
+            increment: 1.0,
+            heap: VariableHeap::new(count),
+            seen: vec![false; count],
+            restarts: 0,
+            named: (1..=count as u32)
+                .map(|variable| cnf.name(variable).is_some())
+                .collect(),
+            unsatisfiable: false,
+        };
+
+        for clause in cnf.clauses() {
+            this.add_clause(clause);
+        }
+
+        this.max_learnt_count = (this.clauses.len() / 3).max(1000);
+        this
+    }
+
+    /// Adds a clause of DIMACS literals over the variables of the [`Cnf`]
+    /// the solver was created with, e.g. to exclude a previous model.
+    pub fn add_clause(&mut self, clause: &[i32]) {
+        self.backtrack(0);
+
+        let mut literals: Vec<usize> = Vec::new();
+        for &literal in clause {
+            let literal = 2 * (literal.unsigned_abs() as usize - 1) + (literal < 0) as usize;
+            self.occurs[literal] = true;
+
+            // values without a decision hold in every model
+            match value(&self.values, literal) {
+                Some(true) => return,
+                Some(false) => continue,
+                None => {}
+            }
+
+            if literals.contains(&(literal ^ 1)) {
+                return;
+            }
+
+            if !literals.contains(&literal) {
+                literals.push(literal);
+            }
+        }
+
+        match literals.len() {
+            0 => self.unsatisfiable = true,
+            1 => self.assign(literals[0], None),
+            _ => {
+                self.watch(literals, None);
+            }
+        }
+    }
+
+    /// Searches for an assignment satisfying every clause, the value of
+    /// variable `n` is at index `n - 1` of the model.
+    ///
+    /// Variables left unassigned may take either value. Returns `None` if
+    /// there is no such assignment.
+    pub fn solve(&mut self) -> Option<Vec<Option<bool>>> {
+        if self.unsatisfiable {
+            return None;
+        }
+
+        self.backtrack(0);
+        let mut conflicts = 0;
+
+        loop {
+            if let Some(conflict) = self.propagate() {
+                if self.trail_limits.is_empty() {
+                    self.unsatisfiable = true;
+                    return None;
+                }
+
+                conflicts += 1;
+                let (learnt, level) = self.analyze(conflict);
+                self.backtrack(level);
+
+                if learnt.len() == 1 {
+                    self.assign(learnt[0], None);
+                } else {
+                    let literal = learnt[0];
+                    let distance = self.distance(&learnt);
+                    let clause = self.watch(learnt, Some(distance));
+                    self.assign(literal, Some(clause));
+                }
+
+                self.increment /= 0.95;
+                continue;
+            }
+
+            if conflicts >= RESTART_INTERVAL * luby(self.restarts) {
+                conflicts = 0;
+                self.restarts += 1;
+                self.backtrack(0);
+
+                if self.learnt_count > self.max_learnt_count {
+                    self.reduce();
+                    self.max_learnt_count += self.max_learnt_count / 10;
+                }
+                continue;
+            }
+
+            match self.choose() {
+                Some(literal) => {
+                    self.trail_limits.push(self.trail.len());
+                    self.assign(literal, None);
+                }
+                None => return Some(self.model()),
+            }
+        }
+    }
+
+    fn level(&self) -> usize {
+        self.trail_limits.len()
+    }
+
+    fn assign(&mut self, literal: usize, reason: Option<usize>) {
+        let variable = literal >> 1;
+        self.values[variable] = Some(literal & 1 == 0);
+        self.levels[variable] = self.level();
+        self.reasons[variable] = reason;
+        self.trail.push(literal);
+    }
+
+    /// Adds a clause of at least two literals watched by its first two and
+    /// returns its index.
+    fn watch(&mut self, literals: Vec<usize>, distance: Option<usize>) -> usize {
+        let index = self.clauses.len();
+        self.watches[literals[0]].push((index, literals[1]));
+        self.watches[literals[1]].push((index, literals[0]));
+        self.clauses.push(literals);
+        self.distances.push(distance);
+        self.learnt_count += distance.is_some() as usize;
+
+        index
+    }
+
+    /// Undoes every assignment made after decision level `level`.
+    fn backtrack(&mut self, level: usize) {
+        if self.level() <= level {
+            return;
+        }
+
+        for &literal in &self.trail[self.trail_limits[level]..] {
+            let variable = literal >> 1;
+            self.phases[variable] = self.values[variable];
+            self.values[variable] = None;
+            self.reasons[variable] = None;
+            self.heap.push(variable, &self.activity);
+        }
+
+        self.trail.truncate(self.trail_limits[level]);
+        self.trail_limits.truncate(level);
+        self.head = self.trail.len();
+    }
+
+    /// Assigns the literals implied by unit clauses until there are none
+    /// left, returns the clause that became false if any.
+    fn propagate(&mut self) -> Option<usize> {
+        while self.head < self.trail.len() {
+            let false_literal = self.trail[self.head] ^ 1;
+            self.head += 1;
+
+            let mut watchers = std::mem::take(&mut self.watches[false_literal]);
+            let mut conflict = None;
+            let mut i = 0;
+
+            while i < watchers.len() {
+                let (index, blocker) = watchers[i];
+
+                // the clause doesn't need to be visited at all
+                if value(&self.values, blocker) == Some(true) {
+                    i += 1;
+                    continue;
+                }
+
+                let clause = &mut self.clauses[index];
+
+                // keep the false literal second
+                if clause[0] == false_literal {
+                    clause.swap(0, 1);
+                }
+
+                let first = clause[0];
+                if value(&self.values, first) == Some(true) {
+                    watchers[i].1 = first;
+                    i += 1;
+                    continue;
+                }
+
+                let replacement =
+                    (2..clause.len()).find(|&k| value(&self.values, clause[k]) != Some(false));
+
+                if let Some(k) = replacement {
+                    clause.swap(1, k);
+                    self.watches[clause[1]].push((index, first));
+                    watchers.swap_remove(i);
+                    continue;
+                }
+
+                i += 1;
+                if value(&self.values, first) == Some(false) {
+                    conflict = Some(index);
+                    break;
+                }
+
+                self.assign(first, Some(index));
+            }
+
+            self.watches[false_literal] = watchers;
+
+            if conflict.is_some() {
+                return conflict;
+            }
+        }
+
+        None
+    }
+
+    /// Derives a clause from `conflict` with a single literal assigned at
+    /// the current decision level, returns it with that literal first and
+    /// the decision level to jump back to.
+    fn analyze(&mut self, conflict: usize) -> (Vec<usize>, usize) {
+        let mut learnt = vec![0];
+        let mut pending = 0;
+        let mut index = self.trail.len();
+        let mut clause = conflict;
+        // the implied literal of a reason is its first
+        let mut skip = 0;
+
+        loop {
+            for k in skip..self.clauses[clause].len() {
+                let literal = self.clauses[clause][k];
+                let variable = literal >> 1;
+
+                if self.seen[variable] || self.levels[variable] == 0 {
+                    continue;
+                }
+
+                self.seen[variable] = true;
+                self.bump(variable);
+
+                if self.levels[variable] == self.level() {
+                    pending += 1;
+                } else {
+                    learnt.push(literal);
+                }
+            }
+
+            // the most recent literal of the current level in the clause
+            loop {
+                index -= 1;
+                if self.seen[self.trail[index] >> 1] {
+                    break;
+                }
+            }
+
+            let literal = self.trail[index];
+            self.seen[literal >> 1] = false;
+            pending -= 1;
+
+            if pending == 0 {
+                learnt[0] = literal ^ 1;
+                break;
+            }
+
+            clause = self.reasons[literal >> 1].unwrap();
+            skip = 1;
+        }
+
+        // drop the literals whose reason only has other literals of the
+        // clause or values fixed without a decision
+        let redundant = learnt
+            .iter()
+            .map(|&literal| {
+                self.reasons[literal >> 1].is_some_and(|reason| {
+                    self.clauses[reason][1..]
+                        .iter()
+                        .all(|&other| self.seen[other >> 1] || self.levels[other >> 1] == 0)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for &literal in &learnt[1..] {
+            self.seen[literal >> 1] = false;
+        }
+
+        let mut kept = 1;
+        for k in 1..learnt.len() {
+            if !redundant[k] {
+                learnt[kept] = learnt[k];
+                kept += 1;
+            }
+        }
+        learnt.truncate(kept);
+
+        // watch the literal of the highest remaining level second
+        let mut level = 0;
+        for k in 1..learnt.len() {
+            let variable_level = self.levels[learnt[k] >> 1];
+            if variable_level > level {
+                level = variable_level;
+                learnt.swap(1, k);
+            }
+        }
+
+        (learnt, level)
+    }
+
+    /// Returns the number of decision levels the literals of `clause` were
+    /// assigned at.
+    fn distance(&self, clause: &[usize]) -> usize {
+        let mut levels = clause
+            .iter()
+            .map(|&literal| self.levels[literal >> 1])
+            .collect::<Vec<_>>();
+        levels.sort_unstable();
+        levels.dedup();
+
+        levels.len()
+    }
+
+    /// Deletes the half of the learnt clauses spanning the most decision
+    /// levels, except those spanning two at most. Only called without
+    /// decisions, when no learnt clause is the reason of a decided value.
+    fn reduce(&mut self) {
+        let mut candidates = (0..self.clauses.len())
+            .filter(|&index| matches!(self.distances[index], Some(distance) if distance > 2))
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|&index| {
+            std::cmp::Reverse((self.distances[index], self.clauses[index].len()))
+        });
+
+        let mut deleted = vec![false; self.clauses.len()];
+        for &index in &candidates[..candidates.len() / 2] {
+            deleted[index] = true;
+        }
+
+        let mut indices = vec![None; self.clauses.len()];
+        let mut kept = 0;
+        for index in 0..self.clauses.len() {
+            if !deleted[index] {
+                self.clauses.swap(kept, index);
+                self.distances.swap(kept, index);
+                indices[index] = Some(kept);
+                kept += 1;
+            }
+        }
+
+        self.clauses.truncate(kept);
+        self.distances.truncate(kept);
+        self.learnt_count -= candidates.len() / 2;
+
+        for reason in &mut self.reasons {
+            *reason = reason.and_then(|index| indices[index]);
+        }
+
+        for watchers in &mut self.watches {
+            watchers.clear();
+        }
+
+        for (index, clause) in self.clauses.iter().enumerate() {
+            self.watches[clause[0]].push((index, clause[1]));
+            self.watches[clause[1]].push((index, clause[0]));
+        }
+    }
+
+    fn bump(&mut self, variable: usize) {
+        self.activity[variable] += self.increment;
+
+        if self.activity[variable] > 1e100 {
+            for activity in &mut self.activity {
+                *activity *= 1e-100;
+            }
+            self.increment *= 1e-100;
+        }
+
+        self.heap.bumped(variable, &self.activity);
+    }
+
+    /// Returns the literal to decide next, `None` if every variable is
+    /// assigned.
+    fn choose(&mut self) -> Option<usize> {
+        while let Some(variable) = self.heap.pop(&self.activity) {
+            if self.values[variable].is_some() {
+                continue;
+            }
+
+            let positive = 2 * variable;
+            let phase = self.phases[variable]
+                .unwrap_or(self.occurs[positive] && !self.occurs[positive + 1]);
+
+            return Some(positive + !phase as usize);
+        }
+
+        None
+    }
+
+    /// Returns the values of the current total assignment that the added
+    /// clauses need, every value fixed without a decision and for every
+    /// other clause one of its true literals.
+    fn model(&self) -> Vec<Option<bool>> {
+        let mut model = vec![None; self.values.len()];
+
+        let fixed = self
+            .trail_limits
+            .first()
+            .copied()
+            .unwrap_or(self.trail.len());
+        for &literal in &self.trail[..fixed] {
+            model[literal >> 1] = self.values[literal >> 1];
+        }
+
+        for (clause, distance) in self.clauses.iter().zip(&self.distances) {
+            if distance.is_some()
+                || clause
+                    .iter()
+                    .any(|&literal| value(&model, literal) == Some(true))
+            {
+                continue;
+            }
+
+            let literal = clause
+                .iter()
+                .copied()
+                .filter(|&literal| value(&self.values, literal) == Some(true))
+                .max_by_key(|&literal| !self.named[literal >> 1])
+                .unwrap();
+            model[literal >> 1] = self.values[literal >> 1];
+        }
+
+        model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_util::Rng, tseitin, Parser, Solutions, TableauBuilder, TruthTable};
+
+    use super::*;
+
+    /// Returns `true` if `model` makes every clause of `cnf` true, whatever
+    /// the values of its unassigned variables.
+    fn satisfies(cnf: &Cnf, model: &[Option<bool>]) -> bool {
+        cnf.clauses().iter().all(|clause| {
+            clause.iter().any(|&literal| {
+                model[literal.unsigned_abs() as usize - 1] == Some(literal > 0)
+                    || clause.contains(&-literal)
+            })
+        })
+    }
+
+    #[test]
+    fn test_engines_agree() {
+        let mut rng = Rng(0x5a7);
+
+        for _ in 0..200 {
+            let source = rng.source(4);
+            let expr = Parser::new().parse(&source).unwrap();
+            let table = TruthTable::new(&expr).unwrap();
+
+            for expect in [true, false] {
+                let mut cnf = Cnf::new();
+                cnf.assert(&expr, expect);
+                let sat = Solutions::from(&cnf);
+
+                // every model extends exactly one solution, other
+                // assignments none
+                for row in table.rows() {
+                    let extended = sat
+                        .iter()
+                        .filter(|solution| {
+                            solution.iter().all(|(n, v)| {
+                                let index = table.variables().iter().position(|name| name == n);
+                                row.assignment[index.unwrap()] == v
+                            })
+                        })
+                        .count();
+                    let expected = (row.result() == expect) as usize;
+                    assert_eq!(extended, expected, "{}", source);
+                }
+
+                let tableau = TableauBuilder::default().build_expression(&expr, expect);
+                let mut solutions = Solutions::from(&tableau);
+                solutions.clean();
+                assert_eq!(solutions.is_empty(), sat.is_empty(), "{}", source);
+
+                // both engines cover the same models, so every solution
+                // agrees with one of the other engine
+                for (these, those) in [(&sat, &solutions), (&solutions, &sat)] {
+                    for solution in these.iter() {
+                        assert!(
+                            those.iter().any(|other| solution
+                                .iter()
+                                .all(|(n, v)| other.get(n) != Some(!v))),
+                            "{}",
+                            source
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_3_sat() {
+        let mut rng = Rng(0x3547);
+        let count = 12;
+
+        for _ in 0..100 {
+            let mut cnf = Cnf::new();
+            for _ in 0..count {
+                cnf.add_variable(None);
+            }
+
+            // around the ratio of clauses to variables where about half
+            // of the problems are satisfiable
+            for _ in 0..51 {
+                let clause = (0..3)
+                    .map(|_| {
+                        let variable = rng.below(count) as i32 + 1;
+                        if rng.below(2) == 0 {
+                            variable
+                        } else {
+                            -variable
+                        }
+                    })
+                    .collect();
+                cnf.add_clause(clause);
+            }
+
+            let satisfiable = (0..1u32 << count).any(|assignment| {
+                let model: Vec<_> = (0..count).map(|i| Some(assignment >> i & 1 == 1)).collect();
+                satisfies(&cnf, &model)
+            });
+
+            match SatSolver::new(&cnf).solve() {
+                Some(model) => assert!(satisfies(&cnf, &model)),
+                None => assert!(!satisfiable),
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_3_sat_at_scale() {
+        // hard random problems, where the decisions and the learnt clauses
+        // have to be managed well to finish quickly
+        let mut rng = Rng(0x200);
+        let count = 200;
+
+        for _ in 0..2 {
+            let clauses = (0..850)
+                .map(|_| {
+                    (0..3)
+                        .map(|_| {
+                            let variable = rng.below(count) as i32 + 1;
+                            if rng.below(2) == 0 {
+                                variable
+                            } else {
+                                -variable
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            let mut cnf = Cnf::new();
+            for _ in 0..count {
+                cnf.add_variable(None);
+            }
+            for clause in &clauses {
+                cnf.add_clause(clause.clone());
+            }
+
+            let model = SatSolver::new(&cnf).solve();
+            if let Some(model) = &model {
+                assert!(satisfies(&cnf, model));
+            }
+
+            // the same problem as an expression, through its encoding
+            let source = clauses
+                .iter()
+                .map(|clause| {
+                    let literals = clause
+                        .iter()
+                        .map(|&literal| {
+                            if literal > 0 {
+                                format!("x{}", literal)
+                            } else {
+                                format!("~x{}", -literal)
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    format!("({})", literals.join(" | "))
+                })
+                .collect::<Vec<_>>()
+                .join(" & ");
+            let expr = Parser::new().parse(&source).unwrap();
+
+            let mut encoded = Cnf::new();
+            encoded.assert(&expr, true);
+            let encoded_model = SatSolver::new(&encoded).solve();
+            assert_eq!(encoded_model.is_some(), model.is_some());
+            if let Some(model) = &encoded_model {
+                assert!(satisfies(&encoded, model));
+            }
+        }
+    }
+
+    #[test]
+    fn test_under_constrained() {
+        // a total model of either formula would have to be enumerated for
+        // every assignment of the other variables
+        let source = (1..=40)
+            .map(|i| format!("x{}", i))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        let expr = Parser::new().parse(&source).unwrap();
+
+        let mut cnf = Cnf::new();
+        cnf.assert(&expr, true);
+
+        let solutions = Solutions::from(&cnf);
+        assert_eq!(solutions.len(), 40);
+        assert!(solutions
+            .iter()
+            .all(|solution| solution.iter().filter(|&(_, v)| v).count() == 1));
+
+        let mut cnf = Cnf::new();
+        cnf.assert(&expr, false);
+        cnf.assert(&Parser::new().parse("y -> z").unwrap(), true);
+
+        let solutions = Solutions::from(&cnf);
+        assert!(!solutions.is_empty() && solutions.len() <= 3);
+        for solution in solutions.iter() {
+            assert!((1..=40).all(|i| solution.get(format!("x{}", i)) == Some(false)));
+        }
+    }
+
+    #[test]
+    fn test_pigeonhole() {
+        // five pigeons don't fit in four holes
+        let (pigeons, holes) = (5, 4);
+        let variable = |pigeon: i32, hole: i32| pigeon * holes + hole + 1;
+
+        let mut cnf = Cnf::new();
+        for _ in 0..pigeons * holes {
+            cnf.add_variable(None);
+        }
+
+        for pigeon in 0..pigeons {
+            cnf.add_clause((0..holes).map(|hole| variable(pigeon, hole)).collect());
+        }
+
+        for hole in 0..holes {
+            for a in 0..pigeons {
+                for b in a + 1..pigeons {
+                    cnf.add_clause(vec![-variable(a, hole), -variable(b, hole)]);
+                }
+            }
+        }
+
+        assert_eq!(SatSolver::new(&cnf).solve(), None);
+
+        let expr = Parser::new()
+            .parse("((a -> b) & (b -> c)) & (a & ~c)")
+            .unwrap();
+        assert_eq!(SatSolver::new(&tseitin(&expr)).solve(), None);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ExpressionKind, Tableau};
+use crate::{Cnf, ExpressionKind, SatSolver, Tableau};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct Solution {
@@ -12,6 +12,20 @@ impl Solution {
         }
     }
 
+    /// Returns the values of the named variables in a `model` of `cnf`
+    /// found by a [`SatSolver`], leaving out the unassigned ones.
+    pub fn from_model(cnf: &Cnf, model: &[Option<bool>]) -> Self {
+        let mut this = Self::new();
+
+        for variable in 1..=cnf.variable_count() as u32 {
+            if let (Some(name), Some(value)) = (cnf.name(variable), model[variable as usize - 1]) {
+                this.push(name, value);
+            }
+        }
+
+        this
+    }
+
     pub fn get(&self, name: impl AsRef<str>) -> Option<bool> {
         self.variables
             .iter()
@@ -109,6 +123,10 @@ impl From<&Tableau> for Solutions {
     fn from(tableau: &Tableau) -> Self {
         let mut this = Solutions::new();
 
+        if tableau.is_closed() {
+            return this;
+        }
+
         if tableau.branches.is_empty() {
             this.solutions.push(Solution::new());
         }
@@ -136,3 +154,70 @@ impl From<&Tableau> for Solutions {
         this
     }
 }
+
+impl From<&Cnf> for Solutions {
+    /// Enumerates the models of `cnf` with a [`SatSolver`], restricted to
+    /// the named variables, see [`CnfSolutions`].
+    fn from(cnf: &Cnf) -> Self {
+        Self {
+            solutions: CnfSolutions::new(cnf).collect(),
+        }
+    }
+}
+
+/// Iterates over the models of a [`Cnf`] restricted to the named variables,
+/// solving for each one when it is requested.
+///
+/// Like the solutions of a [`Tableau`], a solution leaves out the variables
+/// that don't matter, and no assignment extends two of them.
+///
+/// # Example
+/// ```rust
+/// # use dare::{Cnf, CnfSolutions, Parser};
+/// let expr = Parser::new().parse("a | b").unwrap();
+/// let mut cnf = Cnf::new();
+/// cnf.assert(&expr, true);
+///
+/// let first = CnfSolutions::new(&cnf).next().unwrap();
+/// assert!(first.contains("a", true) || first.contains("b", true));
+/// assert_eq!(CnfSolutions::new(&cnf).count(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CnfSolutions<'a> {
+    cnf: &'a Cnf,
+    solver: SatSolver,
+}
+
+impl<'a> CnfSolutions<'a> {
+    pub fn new(cnf: &'a Cnf) -> Self {
+        Self {
+            cnf,
+            solver: SatSolver::new(cnf),
+        }
+    }
+}
+
+impl Iterator for CnfSolutions<'_> {
+    type Item = Solution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let model = self.solver.solve()?;
+        let solution = Solution::from_model(self.cnf, &model);
+        let blocking = solution
+            .iter()
+            .map(|(name, value)| {
+                let variable = self.cnf.variable(name).unwrap() as i32;
+                if value {
+                    -variable
+                } else {
+                    variable
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // every extension of the solution is a model, exclude them all
+        self.solver.add_clause(&blocking);
+
+        Some(solution)
+    }
+}
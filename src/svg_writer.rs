@@ -0,0 +1,275 @@
+use crate::{
+    layout::{LayoutItem, TableauLayout},
+    BinaryExpression, BinaryOperator, Error, Expression, ExpressionKind, ExpressionWriter,
+    SharedIdMap, Tableau, TableauWriter, UnaryExpression, UnaryOperator,
+};
+
+const FONT_SIZE: f32 = 8.0;
+/// A rough average glyph width, used to make room for long rows.
+const CHAR_WIDTH: f32 = FONT_SIZE * 0.6;
+const PADDING: f32 = 10.0;
+
+/// Escapes `text` for use in SVG text and attributes.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Writes an [`Expression`] as SVG text using the Unicode connectives.
+///
+/// # Example
+/// ```rust
+/// # use dare::{ExpressionWriter, Parser, SvgExpressionWriter};
+/// let expr = Parser::new().parse("~a & (b -> c)").unwrap();
+///
+/// let mut writer = SvgExpressionWriter::default();
+/// writer.write_expression(&expr).unwrap();
+///
+/// assert_eq!(writer.finalize(), "¬a ∧ (b → c)");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SvgExpressionWriter {
+    buffer: String,
+}
+
+impl SvgExpressionWriter {
+    pub fn write_unary(&mut self, unary: &UnaryExpression) -> Result<(), Error> {
+        match unary.operator {
+            UnaryOperator::Negation(_) => self.buffer += "¬",
+        }
+
+        self.write_expression(&unary.operand)
+    }
+
+    pub fn write_binary(&mut self, binary: &BinaryExpression) -> Result<(), Error> {
+        self.write_expression(&binary.lhs)?;
+
+        match binary.operator {
+            BinaryOperator::Conjunction(_) => self.buffer += " ∧ ",
+            BinaryOperator::Disjunction(_) => self.buffer += " ∨ ",
+            BinaryOperator::ExclusiveDisjunction(_) => self.buffer += " ⊕ ",
+            BinaryOperator::Implication(_) => self.buffer += " → ",
+            BinaryOperator::Equivalence(_) => self.buffer += " ↔ ",
+        }
+
+        self.write_expression(&binary.rhs)
+    }
+
+    pub fn finalize(self) -> String {
+        self.buffer
+    }
+}
+
+impl ExpressionWriter for SvgExpressionWriter {
+    type Error = Error;
+
+    fn write_expression(&mut self, expr: &Expression) -> Result<(), Self::Error> {
+        match expr.kind.as_ref() {
+            ExpressionKind::TruthValue(truth_value) => {
+                self.buffer += if truth_value.value { "T" } else { "F" };
+            }
+            ExpressionKind::Atomic(atomic) => self.buffer += &escape(&atomic.ident),
+            ExpressionKind::Paren(paren) => {
+                self.buffer += "(";
+                self.write_expression(&paren.expression)?;
+                self.buffer += ")";
+            }
+            ExpressionKind::Unary(unary) => self.write_unary(unary)?,
+            ExpressionKind::Binary(binary) => self.write_binary(binary)?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a [`Tableau`] as a standalone SVG image, using the same layout as
+/// [`LatexTableauWriter`](crate::LatexTableauWriter) without requiring LaTeX.
+///
+/// # Example
+/// ```rust
+/// # use dare::{Parser, SvgTableauWriter, TableauBuilder, TableauWriter};
+/// let expr = Parser::new().parse("a & ~a").unwrap();
+/// let tableau = TableauBuilder::default().build_expression(&expr, true);
+///
+/// let mut writer = SvgTableauWriter::default();
+/// writer.write_tableau(&tableau).unwrap();
+/// let svg = writer.finalize();
+///
+/// assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+/// assert!(svg.contains(">a ∧ ¬a</text>"));
+/// assert!(svg.contains(">×</text>"));
+/// assert!(svg.ends_with("</svg>\n"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SvgTableauWriter {
+    show_all_ids: bool,
+    id_map: SharedIdMap,
+    min_x: f32,
+    max_x: f32,
+    height: f32,
+    buffer: String,
+}
+
+impl SvgTableauWriter {
+    pub fn show_all_ids(&mut self) {
+        self.show_all_ids = true;
+    }
+
+    pub fn share_ids(&mut self, id_map: &SharedIdMap) {
+        self.id_map = id_map.clone();
+    }
+
+    /// Adds a line of text at picture coordinates `x` and `y`.
+    fn write_text(&mut self, x: f32, y: f32, anchor: &str, content: &str) {
+        self.buffer += &format!(
+            "  <text x=\"{}\" y=\"{}\" text-anchor=\"{}\">{}</text>\n",
+            x,
+            self.height - y,
+            anchor,
+            content
+        );
+    }
+
+    /// Widens the image to fit `text` drawn from `x`, to the left if
+    /// `anchor` is `end` and to the right otherwise.
+    fn fit(&mut self, x: f32, anchor: &str, text: &str) {
+        let width = text.chars().count() as f32 * CHAR_WIDTH;
+
+        if anchor == "end" {
+            self.min_x = self.min_x.min((x - width).floor());
+        } else {
+            self.max_x = self.max_x.max((x + width).ceil());
+        }
+    }
+
+    pub fn finalize(self) -> String {
+        let width = self.max_x - self.min_x + 2.0 * PADDING;
+        let height = self.height + 2.0 * PADDING;
+
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+            viewBox=\"{} {} {} {}\" font-family=\"serif\" font-size=\"{}\" \
+            dominant-baseline=\"middle\">\n{}</svg>\n",
+            width,
+            height,
+            self.min_x - PADDING,
+            -PADDING,
+            width,
+            height,
+            FONT_SIZE,
+            self.buffer
+        )
+    }
+}
+
+impl TableauWriter for SvgTableauWriter {
+    type Error = Error;
+
+    fn write_tableau(&mut self, tableau: &Tableau) -> Result<(), Self::Error> {
+        let layout = TableauLayout::new(tableau, self.show_all_ids);
+        self.min_x = 0.0;
+        self.max_x = layout.width;
+        self.height = layout.height;
+
+        for item in layout.items {
+            match item {
+                LayoutItem::Row {
+                    x,
+                    y,
+                    expectation,
+                    show_id,
+                } => {
+                    let mut writer = SvgExpressionWriter::default();
+                    writer.write_expression(&expectation.expr)?;
+
+                    let expr = writer.finalize();
+                    self.fit(x - 4.0, "end", &expr);
+                    self.write_text(x - 4.0, y, "end", &expr);
+
+                    let value = if expectation.truth_value { "T" } else { "F" };
+                    let mut right = format!(": {}", value);
+
+                    if show_id {
+                        let id = self.id_map.get_id(expectation.id);
+                        self.fit(x - 1.5, "start", &format!("{}   {}", right, id));
+                        right += &format!("<tspan dx=\"6\" fill=\"red\">{}</tspan>", id);
+                    } else {
+                        self.fit(x - 1.5, "start", &right);
+                    }
+
+                    self.write_text(x - 1.5, y, "start", &right);
+                }
+                LayoutItem::Marker { x, y, closed } => {
+                    let marker = if closed { "×" } else { "○" };
+                    self.write_text(x, y, "middle", marker);
+                }
+                LayoutItem::Edge {
+                    from,
+                    control,
+                    to,
+                    label,
+                } => {
+                    self.buffer += &format!(
+                        "  <path d=\"M {} {} Q {} {} {} {}\" fill=\"none\" stroke=\"black\" \
+                        stroke-width=\"0.5\"/>\n",
+                        from.0,
+                        self.height - from.1,
+                        control.0,
+                        self.height - control.1,
+                        to.0,
+                        self.height - to.1,
+                    );
+
+                    if let Some((x, y, id)) = label {
+                        let id = self.id_map.get_id(id);
+                        self.write_text(
+                            x,
+                            y,
+                            "middle",
+                            &format!("<tspan fill=\"#e65ebd\">{}</tspan>", id),
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Parser, TableauBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_svg_tableau() {
+        let expr = Parser::new().parse("a | b").unwrap();
+        let tableau = TableauBuilder::default().build_expression(&expr, true);
+
+        let mut writer = SvgTableauWriter::default();
+        writer.show_all_ids();
+        writer.write_tableau(&tableau).unwrap();
+
+        let expected = [
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"142\" height=\"80\" viewBox=\"-19 -10 142 80\" font-family=\"serif\" font-size=\"8\" dominant-baseline=\"middle\">",
+            "  <text x=\"36\" y=\"0\" text-anchor=\"end\">a ∨ b</text>",
+            "  <text x=\"38.5\" y=\"0\" text-anchor=\"start\">: T<tspan dx=\"6\" fill=\"red\">1</tspan></text>",
+            "  <path d=\"M 40 10 Q 20 21 0 32\" fill=\"none\" stroke=\"black\" stroke-width=\"0.5\"/>",
+            "  <text x=\"-4\" y=\"40\" text-anchor=\"end\">a</text>",
+            "  <text x=\"-1.5\" y=\"40\" text-anchor=\"start\">: T<tspan dx=\"6\" fill=\"red\">2</tspan></text>",
+            "  <text x=\"0\" y=\"50\" text-anchor=\"middle\">○</text>",
+            "  <path d=\"M 40 10 Q 60 21 80 32\" fill=\"none\" stroke=\"black\" stroke-width=\"0.5\"/>",
+            "  <text x=\"70\" y=\"16\" text-anchor=\"middle\"><tspan fill=\"#e65ebd\">1</tspan></text>",
+            "  <text x=\"76\" y=\"40\" text-anchor=\"end\">b</text>",
+            "  <text x=\"78.5\" y=\"40\" text-anchor=\"start\">: T<tspan dx=\"6\" fill=\"red\">3</tspan></text>",
+            "  <text x=\"80\" y=\"50\" text-anchor=\"middle\">○</text>",
+            "</svg>",
+            "",
+        ];
+        assert_eq!(writer.finalize(), expected.join("\n"));
+    }
+}
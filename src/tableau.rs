@@ -1,4 +1,7 @@
-use std::ops::{Deref, DerefMut};
+use std::{
+    collections::HashSet,
+    ops::{Deref, DerefMut},
+};
 
 use crate::{
     AtomicExpression, BinaryExpression, BinaryOperator, Error, Expression, ExpressionKind,
@@ -55,6 +58,10 @@ impl DerefMut for TableauBranch {
 pub struct Tableau {
     pub expectations: Vec<Expectation>,
     pub branches: Vec<TableauBranch>,
+    /// The ids of two conflicting [`Expectation`]s if the branch ending in
+    /// this tableau is closed, the ids are equal for a constant with the wrong
+    /// truth value.
+    pub closed: Option<(u32, u32)>,
 }
 
 impl Tableau {
@@ -78,10 +85,16 @@ impl Tableau {
     }
 
     pub fn append(&mut self, mut other: TableauBranch) {
+        // closed branches are never expanded
+        if self.is_closed() {
+            return;
+        }
+
         self.expectations.append(&mut other.tableau.expectations);
 
         if self.branches.is_empty() {
             self.branches = other.tableau.branches;
+            self.closed = other.tableau.closed;
 
             return;
         }
@@ -91,6 +104,120 @@ impl Tableau {
         }
     }
 
+    /// Returns `true` if the branch ending in this tableau is closed.
+    pub const fn is_closed(&self) -> bool {
+        self.closed.is_some()
+    }
+
+    /// Returns `true` if every branch of this tableau is closed.
+    pub fn is_fully_closed(&self) -> bool {
+        if self.is_closed() {
+            return true;
+        }
+
+        !self.branches.is_empty() && self.branches.iter().all(|branch| branch.is_fully_closed())
+    }
+
+    /// Marks every branch containing conflicting [`Expectation`]s as closed
+    /// and removes everything below the conflict.
+    pub fn close(&mut self) {
+        self.close_with(&mut Vec::new());
+    }
+
+    fn close_with(&mut self, literals: &mut Vec<(String, bool, u32)>) {
+        let depth = literals.len();
+        self.closed = None;
+
+        for expectation in &self.expectations {
+            match expectation.expr.kind.as_ref() {
+                ExpressionKind::TruthValue(truth_value)
+                    if truth_value.value != expectation.truth_value =>
+                {
+                    self.closed = Some((expectation.id, expectation.id));
+                }
+                ExpressionKind::Atomic(atomic) => {
+                    let conflict = literals.iter().find(|(ident, truth_value, _)| {
+                        *ident == atomic.ident && *truth_value != expectation.truth_value
+                    });
+
+                    if let Some(&(_, _, id)) = conflict {
+                        self.closed = Some((id, expectation.id));
+                    }
+
+                    literals.push((
+                        atomic.ident.clone(),
+                        expectation.truth_value,
+                        expectation.id,
+                    ));
+                }
+                _ => {}
+            }
+
+            if self.is_closed() {
+                self.branches.clear();
+                break;
+            }
+        }
+
+        for branch in &mut self.branches {
+            branch.close_with(literals);
+        }
+
+        literals.truncate(depth);
+    }
+
+    /// Returns the [`Expectation`] with `id`, searching every branch.
+    pub fn expectation(&self, id: u32) -> Option<&Expectation> {
+        self.expectations
+            .iter()
+            .find(|expectation| expectation.id == id)
+            .or_else(|| {
+                self.branches
+                    .iter()
+                    .find_map(|branch| branch.expectation(id))
+            })
+    }
+
+    /// Returns this tableau as it looked after the first `count` of `steps`
+    /// were applied, hiding everything created by the remaining steps.
+    pub fn after_steps(&self, steps: &[TableauStep], count: usize) -> Tableau {
+        let hidden = steps
+            .iter()
+            .skip(count)
+            .flat_map(|step| step.branches.iter().flatten())
+            .copied()
+            .collect();
+
+        self.filter(&hidden)
+    }
+
+    fn filter(&self, hidden: &HashSet<u32>) -> Tableau {
+        Tableau {
+            expectations: self
+                .expectations
+                .iter()
+                .filter(|expectation| !hidden.contains(&expectation.id))
+                .cloned()
+                .collect(),
+            // the expectations of a branch are added by the same step, so the
+            // branches of a rule that hasn't fired yet are empty
+            branches: self
+                .branches
+                .iter()
+                .filter(|branch| {
+                    branch
+                        .expectations
+                        .iter()
+                        .any(|expectation| !hidden.contains(&expectation.id))
+                })
+                .map(|branch| TableauBranch::new(branch.filter(hidden), branch.expectation))
+                .collect(),
+            closed: self
+                .closed
+                .filter(|(a, b)| !hidden.contains(a) && !hidden.contains(b)),
+        }
+    }
+
     pub fn width(&self) -> usize {
         if self.branches.is_empty() {
             return 1;
@@ -103,18 +230,332 @@ impl Tableau {
     }
 }
 
+/// A tableau rule, named after the connective it expands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TableauRule {
+    Negation,
+    Conjunction,
+    Disjunction,
+    ExclusiveDisjunction,
+    Implication,
+    Equivalence,
+}
+
+impl std::fmt::Display for TableauRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableauRule::Negation => f.write_str("negation"),
+            TableauRule::Conjunction => f.write_str("conjunction"),
+            TableauRule::Disjunction => f.write_str("disjunction"),
+            TableauRule::ExclusiveDisjunction => f.write_str("exclusive disjunction"),
+            TableauRule::Implication => f.write_str("implication"),
+            TableauRule::Equivalence => f.write_str("equivalence"),
+        }
+    }
+}
+
+/// A single rule application recorded by a traced [`TableauBuilder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableauStep {
+    /// The id of the expanded [`Expectation`].
+    pub expectation: u32,
+    pub rule: TableauRule,
+    pub truth_value: bool,
+    /// The ids of the created expectations, one list per branch.
+    pub branches: Vec<Vec<u32>>,
+}
+
+/// Returns `expr` without any parentheses around it.
+fn strip_parens(mut expr: &Expression) -> &Expression {
+    while let ExpressionKind::Paren(paren) = expr.kind.as_ref() {
+        expr = &paren.expression;
+    }
+
+    expr
+}
+
+/// Builds a [`Tableau`] from the top down.
+///
+/// The expectations added together by a rule share a node and the rule of
+/// each is applied in turn to every open leaf below it. Every literal is
+/// checked against the literals above it as soon as it is added, so closed
+/// branches are never expanded.
 #[derive(Default)]
 pub struct TableauBuilder {
     id: u32,
+    trace: Option<Vec<TableauStep>>,
+    /// The literals on the branch being built, with the id of their
+    /// expectation.
+    path: Vec<(String, bool, u32)>,
 }
 
 impl TableauBuilder {
+    /// Creates a [`TableauBuilder`] recording every rule application, see
+    /// [`Self::steps`].
+    pub fn traced() -> Self {
+        Self {
+            trace: Some(Vec::new()),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the recorded rule applications in the order the rules fired.
+    pub fn steps(&self) -> &[TableauStep] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
     pub fn next_id(&mut self) -> u32 {
         let id = self.id;
         self.id += 1;
         id
     }
 
+    /// Records the start of a rule application, the children are filled in by
+    /// [`Self::end_step`] once they are built.
+    fn begin_step(&mut self, expectation: u32, rule: TableauRule, truth_value: bool) -> usize {
+        let Some(trace) = &mut self.trace else {
+            return 0;
+        };
+
+        trace.push(TableauStep {
+            expectation,
+            rule,
+            truth_value,
+            branches: Vec::new(),
+        });
+
+        trace.len() - 1
+    }
+
+    fn end_step(&mut self, step: usize, branches: &[TableauBranch]) {
+        if let Some(trace) = &mut self.trace {
+            trace[step].branches = branches
+                .iter()
+                .map(|branch| branch.expectations.iter().map(|e| e.id).collect())
+                .collect();
+        }
+    }
+
+    /// Pushes the literals of `tableau` to the path, stopping at the first
+    /// one that conflicts with the path and returning the ids of both.
+    fn push_literals(&mut self, tableau: &Tableau) -> Option<(u32, u32)> {
+        for expectation in &tableau.expectations {
+            match expectation.expr.kind.as_ref() {
+                ExpressionKind::TruthValue(truth_value)
+                    if truth_value.value != expectation.truth_value =>
+                {
+                    return Some((expectation.id, expectation.id));
+                }
+                ExpressionKind::Atomic(atomic) => {
+                    let conflict = self.path.iter().find(|(ident, truth_value, _)| {
+                        *ident == atomic.ident && *truth_value != expectation.truth_value
+                    });
+
+                    if let Some(&(_, _, id)) = conflict {
+                        return Some((id, expectation.id));
+                    }
+
+                    self.path.push((
+                        atomic.ident.clone(),
+                        expectation.truth_value,
+                        expectation.id,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    /// Builds a node of the given expectations below the current path and
+    /// expands it unless it is closed.
+    fn build_node(&mut self, expectations: &[(&Expression, bool)]) -> Tableau {
+        let depth = self.path.len();
+
+        let mut tableau = Tableau {
+            expectations: expectations
+                .iter()
+                .map(|&(expr, expect)| {
+                    Expectation::new(strip_parens(expr).clone(), expect, self.next_id())
+                })
+                .collect(),
+            branches: Vec::new(),
+            closed: None,
+        };
+        tableau.closed = self.push_literals(&tableau);
+
+        for i in 0..tableau.expectations.len() {
+            let expectation = tableau.expectations[i].clone();
+
+            if matches!(
+                expectation.expr.kind.as_ref(),
+                ExpressionKind::Unary(_) | ExpressionKind::Binary(_)
+            ) {
+                self.expand_leaves(&mut tableau, &expectation);
+            }
+        }
+
+        self.path.truncate(depth);
+        tableau
+    }
+
+    /// Applies the rule of `expectation` to every open leaf of `tableau`,
+    /// whose own literals are already on the path.
+    fn expand_leaves(&mut self, tableau: &mut Tableau, expectation: &Expectation) {
+        if tableau.is_closed() {
+            return;
+        }
+
+        if tableau.branches.is_empty() {
+            tableau.branches = self.expand(expectation);
+            return;
+        }
+
+        for branch in &mut tableau.branches {
+            let depth = self.path.len();
+            self.push_literals(&branch.tableau);
+            self.expand_leaves(&mut branch.tableau, expectation);
+            self.path.truncate(depth);
+        }
+    }
+
+    /// Applies the rule of `expectation`, returning the branches it creates.
+    fn expand(&mut self, expectation: &Expectation) -> Vec<TableauBranch> {
+        let (id, expect) = (expectation.id, expectation.truth_value);
+
+        match expectation.expr.kind.as_ref() {
+            ExpressionKind::TruthValue(_) | ExpressionKind::Atomic(_) => Vec::new(),
+            ExpressionKind::Paren(_) => unreachable!("expectations never hold parentheses"),
+            ExpressionKind::Unary(unary) => match unary.operator {
+                UnaryOperator::Negation(_) => self.expand_negation(id, &unary.operand, expect),
+            },
+            ExpressionKind::Binary(binary) => {
+                let (lhs, rhs) = (&binary.lhs, &binary.rhs);
+
+                match binary.operator {
+                    BinaryOperator::Conjunction(_) => self.expand_conjunction(id, lhs, rhs, expect),
+                    BinaryOperator::Disjunction(_) => self.expand_disjunction(id, lhs, rhs, expect),
+                    // `a ^ b` expands like the negated equivalence, but keeps its own label
+                    BinaryOperator::ExclusiveDisjunction(_) => self.expand_equivalence_with(
+                        TableauRule::ExclusiveDisjunction,
+                        id,
+                        lhs,
+                        rhs,
+                        expect,
+                        !expect,
+                    ),
+                    BinaryOperator::Implication(_) => self.expand_implication(id, lhs, rhs, expect),
+                    BinaryOperator::Equivalence(_) => self.expand_equivalence_with(
+                        TableauRule::Equivalence,
+                        id,
+                        lhs,
+                        rhs,
+                        expect,
+                        expect,
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Applies `rule` to expectation `id`, building a branch for every list
+    /// of expectations in `branches`.
+    fn apply(
+        &mut self,
+        id: u32,
+        rule: TableauRule,
+        expect: bool,
+        branches: &[&[(&Expression, bool)]],
+    ) -> Vec<TableauBranch> {
+        let step = self.begin_step(id, rule, expect);
+
+        let branches = branches
+            .iter()
+            .map(|expectations| TableauBranch::new(self.build_node(expectations), id))
+            .collect::<Vec<_>>();
+
+        self.end_step(step, &branches);
+        branches
+    }
+
+    fn expand_negation(
+        &mut self,
+        id: u32,
+        operand: &Expression,
+        expect: bool,
+    ) -> Vec<TableauBranch> {
+        self.apply(id, TableauRule::Negation, expect, &[&[(operand, !expect)]])
+    }
+
+    fn expand_conjunction(
+        &mut self,
+        id: u32,
+        lhs: &Expression,
+        rhs: &Expression,
+        expect: bool,
+    ) -> Vec<TableauBranch> {
+        let rule = TableauRule::Conjunction;
+
+        if expect {
+            self.apply(id, rule, expect, &[&[(lhs, true), (rhs, true)]])
+        } else {
+            self.apply(id, rule, expect, &[&[(lhs, false)], &[(rhs, false)]])
+        }
+    }
+
+    fn expand_disjunction(
+        &mut self,
+        id: u32,
+        lhs: &Expression,
+        rhs: &Expression,
+        expect: bool,
+    ) -> Vec<TableauBranch> {
+        let rule = TableauRule::Disjunction;
+
+        if expect {
+            self.apply(id, rule, expect, &[&[(lhs, true)], &[(rhs, true)]])
+        } else {
+            self.apply(id, rule, expect, &[&[(lhs, false), (rhs, false)]])
+        }
+    }
+
+    fn expand_implication(
+        &mut self,
+        id: u32,
+        lhs: &Expression,
+        rhs: &Expression,
+        expect: bool,
+    ) -> Vec<TableauBranch> {
+        let rule = TableauRule::Implication;
+
+        if expect {
+            self.apply(id, rule, expect, &[&[(lhs, false)], &[(rhs, true)]])
+        } else {
+            self.apply(id, rule, expect, &[&[(lhs, true), (rhs, false)]])
+        }
+    }
+
+    /// Expands expectation `id` into a branch where `lhs` is true and one
+    /// where it is false, `rhs` has the same value as `lhs` if `equal` and
+    /// the opposite value otherwise.
+    fn expand_equivalence_with(
+        &mut self,
+        rule: TableauRule,
+        id: u32,
+        lhs: &Expression,
+        rhs: &Expression,
+        expect: bool,
+        equal: bool,
+    ) -> Vec<TableauBranch> {
+        self.apply(
+            id,
+            rule,
+            expect,
+            &[&[(lhs, true), (rhs, equal)], &[(lhs, false), (rhs, !equal)]],
+        )
+    }
+
     #[must_use]
     pub fn build_truth_value(
         &mut self,
@@ -122,10 +563,7 @@ impl TableauBuilder {
         _truth_value: &TruthValueExpression,
         expect: bool,
     ) -> Tableau {
-        Tableau {
-            expectations: vec![Expectation::new(expr.clone(), expect, self.next_id())],
-            branches: Vec::new(),
-        }
+        self.build_node(&[(expr, expect)])
     }
 
     #[must_use]
@@ -135,10 +573,7 @@ impl TableauBuilder {
         _atomic: &AtomicExpression,
         expect: bool,
     ) -> Tableau {
-        Tableau {
-            expectations: vec![Expectation::new(expr.clone(), expect, self.next_id())],
-            branches: Vec::new(),
-        }
+        self.build_node(&[(expr, expect)])
     }
 
     #[must_use]
@@ -153,221 +588,374 @@ impl TableauBuilder {
 
     #[must_use]
     pub fn build_negation(&mut self, operand: &Expression, expect: bool) -> Tableau {
-        self.build_expression(&operand, !expect)
+        self.build_expression(operand, !expect)
     }
 
     #[must_use]
     pub fn build_unary(
         &mut self,
         expr: &Expression,
-        unary: &UnaryExpression,
+        _unary: &UnaryExpression,
         expect: bool,
     ) -> Tableau {
-        let id = self.next_id();
-
-        let tableau = match unary.operator {
-            UnaryOperator::Negation(_) => self.build_expression(&unary.operand, !expect),
-        };
-
-        Tableau {
-            expectations: vec![Expectation::new(expr.clone(), expect, id)],
-            branches: vec![TableauBranch::new(tableau, id)],
-        }
+        self.build_node(&[(expr, expect)])
     }
 
     #[must_use]
     pub fn build_conjunction(
         &mut self,
         expr: &Expression,
-        lhs: &Expression,
-        rhs: &Expression,
+        _lhs: &Expression,
+        _rhs: &Expression,
         expect: bool,
     ) -> Tableau {
-        let id = self.next_id();
-
-        if expect == true {
-            let mut lhs_tableau = self.build_expression(lhs, true);
-            let rhs_tableau = self.build_expression(rhs, true);
-
-            lhs_tableau.append(TableauBranch::new(rhs_tableau, id));
-
-            Tableau {
-                expectations: vec![Expectation::new(expr.clone(), true, id)],
-                branches: vec![TableauBranch::new(lhs_tableau, id)],
-            }
-        } else {
-            let lhs_tableau = self.build_expression(lhs, false);
-            let rhs_tableau = self.build_expression(rhs, false);
-
-            Tableau {
-                expectations: vec![Expectation::new(expr.clone(), false, id)],
-                branches: vec![
-                    TableauBranch::new(lhs_tableau, id),
-                    TableauBranch::new(rhs_tableau, id),
-                ],
-            }
-        }
+        self.build_node(&[(expr, expect)])
     }
 
     #[must_use]
     pub fn build_disjunction(
         &mut self,
         expr: &Expression,
-        lhs: &Expression,
-        rhs: &Expression,
+        _lhs: &Expression,
+        _rhs: &Expression,
         expect: bool,
     ) -> Tableau {
-        let id = self.next_id();
-
-        if expect == true {
-            let lhs_tableau = self.build_expression(lhs, true);
-            let rhs_tableau = self.build_expression(rhs, true);
-
-            Tableau {
-                expectations: vec![Expectation::new(expr.clone(), true, id)],
-                branches: vec![
-                    TableauBranch::new(lhs_tableau, id),
-                    TableauBranch::new(rhs_tableau, id),
-                ],
-            }
-        } else {
-            let mut lhs_tableau = self.build_expression(lhs, false);
-            let rhs_tableau = self.build_expression(rhs, false);
-
-            lhs_tableau.append(TableauBranch::new(rhs_tableau, id));
-
-            Tableau {
-                expectations: vec![Expectation::new(expr.clone(), true, id)],
-                branches: vec![TableauBranch::new(lhs_tableau, id)],
-            }
-        }
+        self.build_node(&[(expr, expect)])
     }
 
     #[must_use]
     pub fn build_exclusive(
         &mut self,
         expr: &Expression,
-        lhs: &Expression,
-        rhs: &Expression,
+        _lhs: &Expression,
+        _rhs: &Expression,
         expect: bool,
     ) -> Tableau {
-        self.build_equivalence(expr, lhs, rhs, !expect)
+        self.build_node(&[(expr, expect)])
     }
 
     #[must_use]
     pub fn build_implication(
         &mut self,
         expr: &Expression,
-        lhs: &Expression,
-        rhs: &Expression,
+        _lhs: &Expression,
+        _rhs: &Expression,
         expect: bool,
     ) -> Tableau {
-        let id = self.next_id();
-
-        if expect == true {
-            let lhs_tableau = self.build_expression(lhs, false);
-            let rhs_tableau = self.build_expression(rhs, true);
-
-            Tableau {
-                expectations: vec![Expectation::new(expr.clone(), true, id)],
-                branches: vec![
-                    TableauBranch::new(lhs_tableau, id),
-                    TableauBranch::new(rhs_tableau, id),
-                ],
-            }
-        } else {
-            let mut lhs_tableau = self.build_expression(lhs, true);
-            let rhs_tableau = self.build_expression(rhs, false);
-
-            lhs_tableau.append(TableauBranch::new(rhs_tableau, id));
-
-            Tableau {
-                expectations: vec![Expectation::new(expr.clone(), false, id)],
-                branches: vec![TableauBranch::new(lhs_tableau, id)],
-            }
-        }
+        self.build_node(&[(expr, expect)])
     }
 
     #[must_use]
     pub fn build_equivalence(
         &mut self,
         expr: &Expression,
-        lhs: &Expression,
-        rhs: &Expression,
+        _lhs: &Expression,
+        _rhs: &Expression,
         expect: bool,
     ) -> Tableau {
-        let id = self.next_id();
+        self.build_node(&[(expr, expect)])
+    }
 
-        if expect == true {
-            let mut lhs_a = self.build_expression(lhs, true);
-            let rhs_a = self.build_expression(rhs, true);
+    #[must_use]
+    pub fn build_binary(
+        &mut self,
+        expr: &Expression,
+        _binary: &BinaryExpression,
+        expect: bool,
+    ) -> Tableau {
+        self.build_node(&[(expr, expect)])
+    }
 
-            lhs_a.append(TableauBranch::new(rhs_a, id));
+    #[must_use]
+    pub fn build_expression(&mut self, expr: &Expression, expect: bool) -> Tableau {
+        self.build_node(&[(expr, expect)])
+    }
 
-            let mut lhs_b = self.build_expression(lhs, false);
-            let rhs_b = self.build_expression(rhs, false);
+    /// Builds a tableau expecting every premise to be true and the conclusion
+    /// to be false, so the entailment holds if every branch closes.
+    #[must_use]
+    pub fn build_entailment(
+        &mut self,
+        premises: &[Expression],
+        conclusion: &Expression,
+    ) -> Tableau {
+        let expectations = premises
+            .iter()
+            .map(|premise| (premise, true))
+            .chain([(conclusion, false)])
+            .collect::<Vec<_>>();
 
-            lhs_b.append(TableauBranch::new(rhs_b, id));
+        self.build_node(&expectations)
+    }
+}
 
-            Tableau {
-                expectations: vec![Expectation::new(expr.clone(), true, id)],
-                branches: vec![TableauBranch::new(lhs_a, id), TableauBranch::new(lhs_b, id)],
-            }
-        } else {
-            let mut lhs_a = self.build_expression(lhs, true);
-            let rhs_a = self.build_expression(rhs, false);
+#[cfg(test)]
+mod tests {
+    use crate::{test_util::Rng, Solution, Solutions, TableauExpander, TruthTable, TruthTableRow};
 
-            lhs_a.append(TableauBranch::new(rhs_a, id));
+    use super::*;
 
-            let mut lhs_b = self.build_expression(lhs, false);
-            let rhs_b = self.build_expression(rhs, true);
+    fn build(source: &str, expect: bool) -> Tableau {
+        let expr = Parser::new().parse(source).unwrap();
+        TableauBuilder::default().build_expression(&expr, expect)
+    }
 
-            lhs_b.append(TableauBranch::new(rhs_b, id));
+    fn leaves(tableau: &Tableau) -> Vec<&Tableau> {
+        if tableau.branches.is_empty() {
+            return vec![tableau];
+        }
 
-            Tableau {
-                expectations: vec![Expectation::new(expr.clone(), false, id)],
-                branches: vec![TableauBranch::new(lhs_a, id), TableauBranch::new(lhs_b, id)],
-            }
+        tableau
+            .branches
+            .iter()
+            .flat_map(|branch| leaves(branch))
+            .collect()
+    }
+
+    /// Returns every path from the root to a leaf as `expr : T/F` rows.
+    fn paths(tableau: &Tableau) -> Vec<Vec<String>> {
+        let rows = tableau
+            .expectations
+            .iter()
+            .map(|expectation| {
+                let value = if expectation.truth_value { "T" } else { "F" };
+                format!("{} : {}", expectation.expr, value)
+            })
+            .collect::<Vec<_>>();
+
+        if tableau.branches.is_empty() {
+            return vec![rows];
         }
+
+        tableau
+            .branches
+            .iter()
+            .flat_map(|branch| paths(branch))
+            .map(|path| rows.iter().cloned().chain(path).collect())
+            .collect()
     }
 
-    #[must_use]
-    pub fn build_binary(
-        &mut self,
-        expr: &Expression,
-        binary: &BinaryExpression,
-        expect: bool,
-    ) -> Tableau {
-        match binary.operator {
-            BinaryOperator::Conjunction(_) => {
-                self.build_conjunction(expr, &binary.lhs, &binary.rhs, expect)
-            }
-            BinaryOperator::Disjunction(_) => {
-                self.build_disjunction(expr, &binary.lhs, &binary.rhs, expect)
-            }
-            BinaryOperator::ExclusiveDisjunction(_) => {
-                self.build_exclusive(expr, &binary.lhs, &binary.rhs, expect)
-            }
-            BinaryOperator::Implication(_) => {
-                self.build_implication(expr, &binary.lhs, &binary.rhs, expect)
+    #[test]
+    fn test_rules() {
+        macro_rules! rule_tests {
+            ($($source:literal : $expect:literal => [$([$($row:literal),*]),*]),* $(,)?) => {$({
+                let expected: Vec<Vec<&str>> = vec![$(vec![$($row),*]),*];
+                assert_eq!(paths(&build($source, $expect)), expected, "{} : {}", $source, $expect);
+            })*};
+        }
+
+        rule_tests! {
+            "a" : true => [["a : T"]],
+            "a" : false => [["a : F"]],
+            "1" : true => [["1 : T"]],
+            "(a)" : false => [["a : F"]],
+            "~a" : true => [["~a : T", "a : F"]],
+            "~a" : false => [["~a : F", "a : T"]],
+            "a & b" : true => [["a & b : T", "a : T", "b : T"]],
+            "a & b" : false => [["a & b : F", "a : F"], ["a & b : F", "b : F"]],
+            "a | b" : true => [["a | b : T", "a : T"], ["a | b : T", "b : T"]],
+            "a | b" : false => [["a | b : F", "a : F", "b : F"]],
+            "a -> b" : true => [["a -> b : T", "a : F"], ["a -> b : T", "b : T"]],
+            "a -> b" : false => [["a -> b : F", "a : T", "b : F"]],
+            "a <-> b" : true => [
+                ["a <-> b : T", "a : T", "b : T"],
+                ["a <-> b : T", "a : F", "b : F"]
+            ],
+            "a <-> b" : false => [
+                ["a <-> b : F", "a : T", "b : F"],
+                ["a <-> b : F", "a : F", "b : T"]
+            ],
+            "a ^ b" : true => [
+                ["a ^ b : T", "a : T", "b : F"],
+                ["a ^ b : T", "a : F", "b : T"]
+            ],
+            "a ^ b" : false => [
+                ["a ^ b : F", "a : T", "b : T"],
+                ["a ^ b : F", "a : F", "b : F"]
+            ],
+        }
+    }
+
+    #[test]
+    fn test_solutions_match_interpreter() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+        for _ in 0..300 {
+            let source = rng.source(4);
+            let expr = Parser::new().parse(&source).unwrap();
+            let table = TruthTable::new(&expr).unwrap();
+
+            for (expect, tableau) in [
+                (true, build(&source, true)),
+                (false, build(&source, false)),
+                (true, TableauExpander::default().expand(&expr, true)),
+                (false, TableauExpander::default().expand(&expr, false)),
+            ] {
+                let mut solutions = Solutions::from(&tableau);
+                solutions.clean();
+
+                let consistent = |solution: &Solution, row: &TruthTableRow| {
+                    solution.iter().all(|(name, value)| {
+                        let i = table.variables().iter().position(|v| v == name).unwrap();
+                        row.assignment[i] == value
+                    })
+                };
+
+                for row in table.rows() {
+                    let solved = solutions.iter().any(|solution| consistent(solution, row));
+                    assert_eq!(solved, row.result() == expect, "{} : {}", source, expect);
+                }
             }
-            BinaryOperator::Equivalence(_) => {
-                self.build_equivalence(expr, &binary.lhs, &binary.rhs, expect)
+        }
+    }
+
+    #[test]
+    fn test_steps() {
+        let expr = Parser::new().parse("~(a | b) & c").unwrap();
+        let mut builder = TableauBuilder::traced();
+        let tableau = builder.build_expression(&expr, true);
+
+        let steps = builder
+            .steps()
+            .iter()
+            .map(|step| {
+                let expectation = tableau.expectation(step.expectation).unwrap();
+                let branches = step
+                    .branches
+                    .iter()
+                    .map(|branch| {
+                        branch
+                            .iter()
+                            .map(|&id| tableau.expectation(id).unwrap().expr.to_string())
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+
+                (step.rule, expectation.expr.to_string(), branches)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            steps,
+            [
+                (
+                    TableauRule::Conjunction,
+                    String::from("~(a | b) & c"),
+                    vec![vec![String::from("~(a | b)"), String::from("c")]]
+                ),
+                (
+                    TableauRule::Negation,
+                    String::from("~(a | b)"),
+                    vec![vec![String::from("a | b")]]
+                ),
+                (
+                    TableauRule::Disjunction,
+                    String::from("a | b"),
+                    vec![vec![String::from("a"), String::from("b")]]
+                ),
+            ]
+        );
+
+        let expected: [&[&[&str]]; 4] = [
+            &[&["~(a | b) & c : T"]],
+            &[&["~(a | b) & c : T", "~(a | b) : T", "c : T"]],
+            &[&["~(a | b) & c : T", "~(a | b) : T", "c : T", "a | b : F"]],
+            &[&[
+                "~(a | b) & c : T",
+                "~(a | b) : T",
+                "c : T",
+                "a | b : F",
+                "a : F",
+                "b : F",
+            ]],
+        ];
+
+        for (count, expected) in expected.iter().enumerate() {
+            let after = tableau.after_steps(builder.steps(), count);
+            assert_eq!(paths(&after), *expected, "after {} steps", count);
+        }
+
+        assert!(TableauBuilder::default().steps().is_empty());
+
+        let expr = Parser::new().parse("a ^ b").unwrap();
+        let mut builder = TableauBuilder::traced();
+        let _ = builder.build_expression(&expr, true);
+        let step = &builder.steps()[0];
+        assert_eq!(step.rule, TableauRule::ExclusiveDisjunction);
+        assert!(step.truth_value);
+
+        // rules are never applied below a closed branch, so every step adds
+        // expectations that remain in the tableau
+        let expr = Parser::new().parse("(a & ~a) & (b | c)").unwrap();
+        let mut builder = TableauBuilder::traced();
+        let tableau = builder.build_expression(&expr, true);
+
+        let rules = builder
+            .steps()
+            .iter()
+            .map(|step| step.rule)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rules,
+            [
+                TableauRule::Conjunction,
+                TableauRule::Conjunction,
+                TableauRule::Negation
+            ]
+        );
+
+        for step in builder.steps() {
+            for &id in step.branches.iter().flatten() {
+                assert!(tableau.expectation(id).is_some());
             }
         }
     }
 
-    #[must_use]
-    pub fn build_expression(&mut self, expr: &Expression, expect: bool) -> Tableau {
-        match expr.kind.as_ref() {
-            ExpressionKind::TruthValue(value) => self.build_truth_value(expr, value, expect),
-            ExpressionKind::Atomic(atomic) => self.build_atomic(expr, atomic, expect),
-            ExpressionKind::Paren(paren) => self.build_paren(expr, paren, expect),
-            ExpressionKind::Unary(unary) => self.build_unary(expr, unary, expect),
-            ExpressionKind::Binary(binary) => self.build_binary(expr, binary, expect),
+    #[test]
+    fn test_closure() {
+        let tableau = build("a & ~a", true);
+        assert!(tableau.is_fully_closed());
+
+        let (first, second) = leaves(&tableau)[0].closed.unwrap();
+        let ids = leaves(&tableau)[0]
+            .expectations
+            .iter()
+            .map(|expectation| expectation.id)
+            .collect::<Vec<_>>();
+        assert!(first < second);
+        assert!(ids.contains(&second));
+
+        let tableau = build("a | ~a", false);
+        assert!(tableau.is_fully_closed());
+
+        let tableau = build("(a | b) & ~a", true);
+        assert!(!tableau.is_fully_closed());
+        let closed = leaves(&tableau)
+            .iter()
+            .map(|leaf| leaf.is_closed())
+            .collect::<Vec<_>>();
+        assert_eq!(closed, [true, false]);
+
+        let tableau = build("1", false);
+        assert_eq!(tableau.closed, Some((0, 0)));
+
+        // closed branches aren't expanded any further
+        let tableau = build("(a & ~a) & (b | c | d)", true);
+        assert!(tableau.is_fully_closed());
+        assert_eq!(tableau.width(), 1);
+
+        // the per-connective builders close branches like build_expression
+        for source in ["a & ~a", "(a | b) & ~a & ~b", "~(a -> a)", "~(a <-> a)"] {
+            let expr = Parser::new().parse(source).unwrap();
+            let mut builder = TableauBuilder::default();
+            let tableau = match expr.kind.as_ref() {
+                ExpressionKind::Unary(unary) => builder.build_unary(&expr, unary, true),
+                ExpressionKind::Binary(binary) => builder.build_binary(&expr, binary, true),
+                _ => unreachable!(),
+            };
+            let expected = build(source, true);
+            assert!(tableau.is_fully_closed());
+            assert_eq!(paths(&tableau), paths(&expected));
         }
     }
 }
-
-#[cfg(test)]
-mod tests {}
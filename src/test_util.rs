@@ -0,0 +1,34 @@
+//! Helpers shared by the unit tests.
+
+/// A xorshift generator so the property tests are reproducible.
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    /// Returns the source of a random fully parenthesized expression over the
+    /// variables `a` to `d` with at most `depth` nested operators.
+    pub fn source(&mut self, depth: u32) -> String {
+        const OPERATORS: [&str; 5] = ["&", "|", "^", "->", "<->"];
+
+        match if depth == 0 { 0 } else { self.below(5) } {
+            0 => String::from(["a", "b", "c", "d"][self.below(4) as usize]),
+            1 => format!("~{}", self.source(depth - 1)),
+            _ => {
+                let operator = OPERATORS[self.below(5) as usize];
+                let lhs = self.source(depth - 1);
+                let rhs = self.source(depth - 1);
+                format!("({} {} {})", lhs, operator, rhs)
+            }
+        }
+    }
+}
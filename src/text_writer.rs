@@ -0,0 +1,309 @@
+use crate::{
+    Error, Expectation, SharedIdMap, Tableau, TableauWriter, TruthTable, TruthTableWriter,
+};
+
+/// Writes a [`TruthTable`] as an aligned plain-text grid.
+///
+/// # Example
+/// ```rust
+/// # use dare::{Parser, TextTruthTableWriter, TruthTable, TruthTableWriter};
+/// let expr = Parser::new().parse("a & b").unwrap();
+/// let table = TruthTable::new(&expr).unwrap();
+///
+/// let mut writer = TextTruthTableWriter::default();
+/// writer.write_truth_table(&table).unwrap();
+///
+/// assert_eq!(
+///     writer.finalize(),
+///     "a | b | a & b\n--+---+------\nT | T | T\nT | F | F\nF | T | F\nF | F | F\n"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TextTruthTableWriter {
+    buffer: String,
+}
+
+impl TextTruthTableWriter {
+    fn write_row(&mut self, cells: &[String], widths: &[usize]) {
+        let mut line = String::new();
+
+        for (i, (cell, width)) in cells.iter().zip(widths).enumerate() {
+            if i > 0 {
+                line += " | ";
+            }
+
+            line += cell;
+            line += &" ".repeat(width - cell.chars().count());
+        }
+
+        self.buffer += line.trim_end();
+        self.buffer += "\n";
+    }
+
+    pub fn finalize(self) -> String {
+        self.buffer
+    }
+}
+
+impl TruthTableWriter for TextTruthTableWriter {
+    type Error = Error;
+
+    fn write_truth_table(&mut self, table: &TruthTable) -> Result<(), Self::Error> {
+        let header = table
+            .variables()
+            .iter()
+            .cloned()
+            .chain(table.columns().iter().map(|column| column.to_string()))
+            .collect::<Vec<_>>();
+
+        let widths = header
+            .iter()
+            .map(|cell| cell.chars().count())
+            .collect::<Vec<_>>();
+
+        self.write_row(&header, &widths);
+
+        let separator = widths
+            .iter()
+            .enumerate()
+            .map(|(i, width)| {
+                // the first and last cells are only padded on one side
+                let padding = if i == 0 || i == widths.len() - 1 {
+                    1
+                } else {
+                    2
+                };
+                "-".repeat(width + padding)
+            })
+            .collect::<Vec<_>>();
+
+        if widths.len() == 1 {
+            self.buffer += &"-".repeat(widths[0]);
+        } else {
+            self.buffer += &separator.join("+");
+        }
+        self.buffer += "\n";
+
+        for row in table.rows() {
+            let cells = row
+                .assignment
+                .iter()
+                .chain(row.values.iter())
+                .map(|&value| String::from(if value { "T" } else { "F" }))
+                .collect::<Vec<_>>();
+
+            self.write_row(&cells, &widths);
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a [`Tableau`] as a tree drawn with Unicode box-drawing characters.
+///
+/// Every leaf gets a column of equal width and branches are laid out side by
+/// side, each [`Expectation`](crate::Expectation) is written as `expr : T/F`
+/// followed by its number. Closed branches end in `×`, open ones in `○`.
+///
+/// # Example
+/// ```rust
+/// # use dare::{Parser, TableauBuilder, TableauWriter, TextTableauWriter};
+/// let expr = Parser::new().parse("a | ~a").unwrap();
+/// let tableau = TableauBuilder::default().build_expression(&expr, false);
+///
+/// let mut writer = TextTableauWriter::default();
+/// writer.write_tableau(&tableau).unwrap();
+///
+/// let expected = [
+///     " a | ~a : F (1)",
+///     "       │",
+///     "   a : F (2)",
+///     "   ~a : F (3)",
+///     "       │",
+///     "   a : T (4)",
+///     "       ×",
+/// ];
+/// assert_eq!(writer.finalize(), expected.join("\n") + "\n");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TextTableauWriter {
+    id_map: SharedIdMap,
+    buffer: String,
+}
+
+impl TextTableauWriter {
+    pub fn share_ids(&mut self, id_map: &SharedIdMap) {
+        self.id_map = id_map.clone();
+    }
+
+    fn label(&self, expectation: &Expectation) -> String {
+        let value = if expectation.truth_value { "T" } else { "F" };
+        format!(
+            "{} : {} ({})",
+            expectation.expr,
+            value,
+            self.id_map.get_id(expectation.id)
+        )
+    }
+
+    fn labels(&self, tableau: &Tableau, labels: &mut Vec<String>) {
+        for expectation in &tableau.expectations {
+            labels.push(self.label(expectation));
+        }
+
+        for branch in &tableau.branches {
+            self.labels(branch, labels);
+        }
+    }
+
+    fn center(text: &str, width: usize) -> String {
+        let length = text.chars().count();
+        let left = (width - length) / 2;
+
+        format!(
+            "{}{}{}",
+            " ".repeat(left),
+            text,
+            " ".repeat(width - length - left)
+        )
+    }
+
+    /// Renders `tableau` as lines of exactly `tableau.width() * column` characters.
+    fn render(&self, tableau: &Tableau, column: usize) -> Vec<String> {
+        let width = tableau.width() * column;
+
+        let mut lines = tableau
+            .expectations
+            .iter()
+            .map(|expectation| Self::center(&self.label(expectation), width))
+            .collect::<Vec<_>>();
+
+        if tableau.branches.is_empty() {
+            let marker = if tableau.is_closed() { "×" } else { "○" };
+            lines.push(Self::center(marker, width));
+            return lines;
+        }
+
+        // the connector joins the center of this tableau to every branch
+        let mut connector = vec![' '; width];
+        let mut offset = 0;
+        let mut centers = Vec::new();
+        for branch in &tableau.branches {
+            let branch_width = branch.width() * column;
+            centers.push(offset + (branch_width - 1) / 2);
+            offset += branch_width;
+        }
+
+        let (first, last) = (centers[0], centers[centers.len() - 1]);
+        if first == last {
+            connector[first] = '│';
+        } else {
+            for c in &mut connector[first..=last] {
+                *c = '─';
+            }
+            for &center in &centers {
+                connector[center] = '┬';
+            }
+            connector[first] = '┌';
+            connector[last] = '┐';
+
+            let center = (width - 1) / 2;
+            connector[center] = if connector[center] == '┬' {
+                '┼'
+            } else {
+                '┴'
+            };
+        }
+        lines.push(connector.into_iter().collect());
+
+        let blocks = tableau
+            .branches
+            .iter()
+            .map(|branch| (self.render(branch, column), branch.width() * column))
+            .collect::<Vec<_>>();
+        let height = blocks
+            .iter()
+            .map(|(block, _)| block.len())
+            .max()
+            .unwrap_or(0);
+
+        for row in 0..height {
+            let mut line = String::new();
+
+            for (block, block_width) in &blocks {
+                match block.get(row) {
+                    Some(text) => line += text,
+                    None => line += &" ".repeat(*block_width),
+                }
+            }
+
+            lines.push(line);
+        }
+
+        lines
+    }
+
+    pub fn finalize(self) -> String {
+        self.buffer
+    }
+}
+
+impl TableauWriter for TextTableauWriter {
+    type Error = Error;
+
+    fn write_tableau(&mut self, tableau: &Tableau) -> Result<(), Self::Error> {
+        // numbers are assigned top-down, before any branch is drawn
+        let mut labels = Vec::new();
+        self.labels(tableau, &mut labels);
+
+        let column = labels
+            .iter()
+            .map(|label| label.chars().count())
+            .max()
+            .unwrap_or(1)
+            + 2;
+
+        for line in self.render(tableau, column) {
+            self.buffer += line.trim_end();
+            self.buffer += "\n";
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_text_truth_table() {
+        let expr = Parser::new().parse("~a").unwrap();
+        let table = TruthTable::new(&expr).unwrap();
+
+        let mut writer = TextTruthTableWriter::default();
+        writer.write_truth_table(&table).unwrap();
+
+        assert_eq!(writer.finalize(), "a | ~a\n--+---\nT | F\nF | T\n");
+    }
+
+    #[test]
+    fn test_text_tableau() {
+        let expr = Parser::new().parse("a -> b").unwrap();
+        let tableau = crate::TableauBuilder::default().build_expression(&expr, true);
+
+        let mut writer = TextTableauWriter::default();
+        writer.write_tableau(&tableau).unwrap();
+
+        let expected = [
+            "         a -> b : T (1)",
+            "       ┌───────┴───────┐",
+            "   a : F (2)       b : T (3)",
+            "       ○               ○",
+            "",
+        ];
+        assert_eq!(writer.finalize(), expected.join("\n"));
+    }
+}
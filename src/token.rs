@@ -32,6 +32,7 @@ pub enum TokenKind {
     Identifier(String),
     Delimiter(Delimiter),
     Assignment(&'static str),
+    Entailment(&'static str),
     UnaryOperator(UnaryOperator),
     BinaryOperator(BinaryOperator),
 }
@@ -40,6 +41,8 @@ pub enum TokenKind {
 pub enum Delimiter {
     Open,
     Close,
+    Comma,
+    Separator,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -89,6 +92,12 @@ impl BinaryOperator {
         }
     }
 
+    /// Returns `true` if `self` and `other` are the same associative operator,
+    /// so chaining them doesn't need parentheses.
+    pub fn associates_with(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other) && self.is_associative()
+    }
+
     pub const fn is_associative(&self) -> bool {
         match self {
             BinaryOperator::Conjunction(_) => true,
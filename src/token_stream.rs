@@ -1,10 +1,11 @@
 use std::{iter::Peekable, str::Chars};
 
-use crate::{BinaryOperator, Delimiter, Error, Span, Token, TokenKind, UnaryOperator};
+use crate::{BinaryOperator, Delimiter, Error, ErrorKind, Span, Token, TokenKind, UnaryOperator};
 
 struct Lexer<'a> {
     index: usize,
     chars: Peekable<Chars<'a>>,
+    line_break: bool,
 }
 
 impl<'a> Lexer<'a> {
@@ -12,6 +13,7 @@ impl<'a> Lexer<'a> {
         Self {
             index: 0,
             chars: source.chars().peekable(),
+            line_break: false,
         }
     }
 
@@ -36,14 +38,22 @@ impl<'a> Lexer<'a> {
 
     fn skip_whitespace(&mut self) {
         while self.peek().map_or(false, char::is_whitespace) {
-            self.next();
+            if self.next() == Some('\n') {
+                self.line_break = true;
+            }
         }
     }
 
+    /// Returns `true` if a line break was skipped since the last call.
+    fn take_line_break(&mut self) -> bool {
+        std::mem::take(&mut self.line_break)
+    }
+
     fn parse_symbol(&mut self) -> Result<TokenKind, Error> {
         let span = self.span();
         let ch = self.next().ok_or_else(|| {
             Error::new()
+                .with_kind(ErrorKind::UnexpectedEndOfFile)
                 .with_msg("unexpected end of file")
                 .with_span(span)
         })?;
@@ -54,6 +64,18 @@ impl<'a> Lexer<'a> {
             ('1', _) => TokenKind::TruthValue(true),
             ('(', _) => TokenKind::Delimiter(Delimiter::Open),
             (')', _) => TokenKind::Delimiter(Delimiter::Close),
+            (',', _) => TokenKind::Delimiter(Delimiter::Comma),
+            (';', _) => TokenKind::Delimiter(Delimiter::Separator),
+            ('⊨', _) => TokenKind::Entailment("⊨"),
+            ('|', Some('=')) => {
+                self.next();
+                TokenKind::Entailment("|=")
+            }
+            ('⊢', _) => TokenKind::Entailment("⊢"),
+            ('|', Some('-')) => {
+                self.next();
+                TokenKind::Entailment("|-")
+            }
             ('¬', _) => TokenKind::UnaryOperator(UnaryOperator::Negation("¬")),
             ('~', _) => TokenKind::UnaryOperator(UnaryOperator::Negation("~")),
             ('!', _) => TokenKind::UnaryOperator(UnaryOperator::Negation("!")),
@@ -95,6 +117,7 @@ impl<'a> Lexer<'a> {
                 let span = self.span();
                 if self.next() != Some('>') {
                     let error = Error::new()
+                        .with_kind(ErrorKind::ExpectedSymbol)
                         .with_msg("expected symbol '>'")
                         .with_span(span + self.span());
 
@@ -109,6 +132,7 @@ impl<'a> Lexer<'a> {
             _ => {
                 return {
                     let error = Error::new()
+                        .with_kind(ErrorKind::UnexpectedSymbol)
                         .with_msg(format!("unexpected symbol '{}'", ch))
                         .with_span(span + self.span());
 
@@ -165,6 +189,7 @@ impl<'a> Lexer<'a> {
 /// A stream of [`Token`]s used by the parser.
 pub struct TokenStream {
     tokens: Vec<Token>,
+    line_breaks: Vec<bool>,
     index: usize,
     eof_span: Span,
 }
@@ -172,18 +197,40 @@ pub struct TokenStream {
 impl TokenStream {
     /// Tries to parse `source` as a [`TokenStream`].
     pub fn parse(source: &str) -> Result<Self, Error> {
+        let mut errors = Vec::new();
+        let this = Self::parse_with_errors(source, &mut errors);
+
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(this),
+        }
+    }
+
+    /// Parses `source` as a [`TokenStream`], pushing an [`Error`] to `errors`
+    /// for every invalid symbol and skipping it.
+    pub fn parse_with_errors(source: &str, errors: &mut Vec<Error>) -> Self {
         let mut lexer = Lexer::new(source);
 
         let mut tokens = Vec::new();
+        let mut line_breaks = Vec::new();
         while !lexer.is_empty() {
-            tokens.push(lexer.parse_token()?);
+            let line_break = lexer.take_line_break();
+
+            match lexer.parse_token() {
+                Ok(token) => {
+                    line_breaks.push(line_break);
+                    tokens.push(token);
+                }
+                Err(error) => errors.push(error),
+            }
         }
 
-        Ok(Self {
+        Self {
             tokens,
+            line_breaks,
             index: 0,
             eof_span: lexer.span(),
-        })
+        }
     }
 
     /// Returns `true` if there are no more [`Token`]s left in `self`.
@@ -201,19 +248,40 @@ impl TokenStream {
         }
     }
 
+    /// Returns the [`Span`] of the last [`Token`] returned by [`Self::next`].
+    ///
+    /// Returns an empty [`Span`] at the start if no [`Token`] has been consumed.
+    pub fn last_span(&self) -> Span {
+        match self.index.checked_sub(1) {
+            Some(index) => self.tokens.get(index).map_or(self.eof_span, Token::span),
+            None => Span::new(0, 0),
+        }
+    }
+
+    /// Returns the [`Span`] at the end of the source.
+    pub fn eof_span(&self) -> Span {
+        self.eof_span
+    }
+
+    /// Returns `true` if the next [`Token`] is the first on a new line.
+    pub fn is_line_break(&self) -> bool {
+        self.line_breaks.get(self.index).copied().unwrap_or(false)
+    }
+
     /// Returns the next [`Token`] in `self` and moves the stream forward by one.
     ///
     /// Returns [`None`] if [`Self::is_empty`].
     pub fn try_next(&mut self) -> Option<&Token> {
-        let token = self.tokens.get(self.index);
+        let token = self.tokens.get(self.index)?;
         self.index += 1;
-        token
+        Some(token)
     }
 
     pub fn next(&mut self) -> Result<&Token, Error> {
         let eof_span = self.eof_span;
         self.try_next().ok_or_else(|| {
             Error::new()
+                .with_kind(ErrorKind::UnexpectedEndOfFile)
                 .with_msg("unexpected end of file")
                 .with_span(eof_span)
         })
@@ -229,6 +297,7 @@ impl TokenStream {
     pub fn peek(&self) -> Result<&Token, Error> {
         self.try_peek().ok_or_else(|| {
             Error::new()
+                .with_kind(ErrorKind::UnexpectedEndOfFile)
                 .with_msg("unexpected end of file")
                 .with_span(self.eof_span)
         })
@@ -238,10 +307,16 @@ impl TokenStream {
         self.try_peek().map(|token| token.kind())
     }
 
+    /// Returns the [`TokenKind`] `n` [`Token`]s ahead of the next one.
+    pub fn try_peek_nth_kind(&self, n: usize) -> Option<&TokenKind> {
+        self.tokens.get(self.index + n).map(|token| token.kind())
+    }
+
     pub fn expect(&mut self, kind: &TokenKind) -> Result<&Token, Error> {
         let token = self.next()?;
         if token.kind() != kind {
             let error = Error::new()
+                .with_kind(ErrorKind::ExpectedToken)
                 .with_msg(format!("expected symbol '{:?}'", kind))
                 .with_span(token.span());
 
@@ -258,8 +333,7 @@ mod tests {
 
     #[test]
     fn token_stream_parsing() {
-        let source =
-            r#"A ab _a _0_a ( ) := : ¬ ~ ! && ∧ & . || ∨ | ⊕ ⊻ ^ -> → ⇒ ⊃ == <-> ↔ ⇔ ≡ T F 1 0"#;
+        let source = r#"A ab _a _0_a ( ) , ; := : ⊨ |= ⊢ |- ¬ ~ ! && ∧ & . || ∨ | ⊕ ⊻ ^ -> → ⇒ ⊃ == <-> ↔ ⇔ ≡ T F 1 0"#;
         let token_stream = TokenStream::parse(source).unwrap();
 
         let tokens = [
@@ -269,8 +343,14 @@ mod tests {
             TokenKind::Identifier(String::from("_0_a")),
             TokenKind::Delimiter(Delimiter::Open),
             TokenKind::Delimiter(Delimiter::Close),
+            TokenKind::Delimiter(Delimiter::Comma),
+            TokenKind::Delimiter(Delimiter::Separator),
             TokenKind::Assignment(":="),
             TokenKind::Assignment(":"),
+            TokenKind::Entailment("⊨"),
+            TokenKind::Entailment("|="),
+            TokenKind::Entailment("⊢"),
+            TokenKind::Entailment("|-"),
             TokenKind::UnaryOperator(UnaryOperator::Negation("¬")),
             TokenKind::UnaryOperator(UnaryOperator::Negation("~")),
             TokenKind::UnaryOperator(UnaryOperator::Negation("!")),
@@ -303,4 +383,30 @@ mod tests {
             assert_eq!(*token.kind(), tokens[i]);
         }
     }
+
+    #[test]
+    fn token_stream_line_breaks() {
+        let mut token_stream = TokenStream::parse("a &\n b\n\nc").unwrap();
+
+        let mut line_breaks = Vec::new();
+        while !token_stream.is_empty() {
+            line_breaks.push(token_stream.is_line_break());
+            token_stream.next().unwrap();
+        }
+
+        assert_eq!(line_breaks, [false, false, true, true]);
+        assert_eq!(token_stream.last_span(), Span::new(8, 1));
+    }
+
+    #[test]
+    fn token_stream_errors() {
+        let mut errors = Vec::new();
+        let token_stream = TokenStream::parse_with_errors("a $ b <- c #", &mut errors);
+
+        assert_eq!(token_stream.tokens.len(), 3);
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[0].spans()[0], Span::new(2, 1));
+
+        assert!(TokenStream::parse("a $ b").is_err());
+    }
 }
@@ -0,0 +1,228 @@
+use crate::{Error, Expression, ExpressionKind, Interpreter};
+
+/// A single row of a [`TruthTable`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TruthTableRow {
+    /// The value of each variable, in the order of [`TruthTable::variables`].
+    pub assignment: Vec<bool>,
+    /// The value of each column, in the order of [`TruthTable::columns`].
+    pub values: Vec<bool>,
+}
+
+impl TruthTableRow {
+    /// Returns the value of the root expression, which is always the last column.
+    pub fn result(&self) -> bool {
+        self.values.last().copied().unwrap_or_default()
+    }
+}
+
+/// A truth table enumerating every assignment of the variables in an [`Expression`].
+///
+/// Besides the variables, the table contains a column for every distinct
+/// subexpression ordered from the innermost to the outermost, so the last column
+/// is always the expression itself.
+///
+/// # Example
+/// ```rust
+/// # use dare::{Parser, TruthTable};
+/// let expr = Parser::new().parse("a -> b").unwrap();
+/// let table = TruthTable::new(&expr).unwrap();
+///
+/// assert_eq!(table.variables(), ["a", "b"]);
+/// assert_eq!(table.rows().len(), 4);
+/// assert!(!table.rows()[1].result());
+/// ```
+#[derive(Clone, Debug)]
+pub struct TruthTable {
+    variables: Vec<String>,
+    columns: Vec<Expression>,
+    rows: Vec<TruthTableRow>,
+}
+
+impl TruthTable {
+    /// Builds the [`TruthTable`] of `expression` using a fresh [`Interpreter`].
+    pub fn new(expression: &Expression) -> Result<Self, Error> {
+        Self::with_interpreter(&Interpreter::new(), expression)
+    }
+
+    /// Builds the [`TruthTable`] of `expression` evaluating every column with a
+    /// copy of `interpreter`.
+    ///
+    /// Rows are ordered like the tables in the README, starting with every
+    /// variable true and counting down to every variable false.
+    pub fn with_interpreter(
+        interpreter: &Interpreter,
+        expression: &Expression,
+    ) -> Result<Self, Error> {
+        let mut variables = Vec::new();
+
+        let mut extractor = interpreter.clone();
+        extractor.atomic_expressions.clear();
+        extractor.extract_atomic_expressions(expression);
+        for atomic in extractor.atomic_expressions {
+            if !variables.contains(&atomic.ident) {
+                variables.push(atomic.ident);
+            }
+        }
+
+        let mut columns = Vec::new();
+        Self::collect_columns(interpreter, expression, &mut columns);
+
+        // the root is always a column, even when it is just a variable
+        if columns.is_empty() {
+            columns.push(expression.clone());
+        }
+
+        let mut rows = Vec::with_capacity(1 << variables.len());
+        for i in (0..1usize << variables.len()).rev() {
+            let mut interpreter = interpreter.clone();
+
+            let assignment = (0..variables.len())
+                .map(|j| i >> (variables.len() - j - 1) & 1 == 1)
+                .collect::<Vec<_>>();
+
+            for (variable, &value) in variables.iter().zip(assignment.iter()) {
+                interpreter.variables.insert(variable.clone(), value);
+            }
+
+            let values = columns
+                .iter()
+                .map(|column| interpreter.interpret(column).map(|value| value.value))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            rows.push(TruthTableRow { assignment, values });
+        }
+
+        Ok(Self {
+            variables,
+            columns,
+            rows,
+        })
+    }
+
+    /// Collects every non-terminal subexpression in post-order, skipping
+    /// duplicates and parentheses.
+    ///
+    /// Names defined in `interpreter` get a column after the columns of their
+    /// definition.
+    fn collect_columns(
+        interpreter: &Interpreter,
+        expression: &Expression,
+        columns: &mut Vec<Expression>,
+    ) {
+        match expression.kind.as_ref() {
+            ExpressionKind::TruthValue(_) => return,
+            ExpressionKind::Atomic(atomic) => match interpreter.definitions.get(&atomic.ident) {
+                Some(definition) => {
+                    Self::collect_columns(interpreter, &definition.expression, columns)
+                }
+                None => return,
+            },
+            ExpressionKind::Paren(paren) => {
+                Self::collect_columns(interpreter, &paren.expression, columns);
+                return;
+            }
+            ExpressionKind::Unary(unary) => {
+                Self::collect_columns(interpreter, &unary.operand, columns)
+            }
+            ExpressionKind::Binary(binary) => {
+                Self::collect_columns(interpreter, &binary.lhs, columns);
+                Self::collect_columns(interpreter, &binary.rhs, columns);
+            }
+        }
+
+        let text = expression.to_string();
+        columns.retain(|column| column.to_string() != text);
+        columns.push(expression.clone());
+    }
+
+    /// Returns the names of all variables in order of first appearance.
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Returns the subexpression of every column.
+    pub fn columns(&self) -> &[Expression] {
+        &self.columns
+    }
+
+    /// Returns all rows.
+    pub fn rows(&self) -> &[TruthTableRow] {
+        &self.rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Parser, TokenStream};
+
+    use super::*;
+
+    #[test]
+    fn test_truth_table() {
+        let parser = Parser::new();
+
+        let expr = parser.parse("(a -> b) & (b -> a) & a").unwrap();
+        let table = TruthTable::new(&expr).unwrap();
+
+        assert_eq!(table.variables(), ["a", "b"]);
+
+        let columns = table
+            .columns()
+            .iter()
+            .map(|column| column.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(columns.last().unwrap(), "(a -> b) & (b -> a) & a");
+        assert!(columns.contains(&String::from("a -> b")));
+        assert!(columns.contains(&String::from("b -> a")));
+
+        let results = table
+            .rows()
+            .iter()
+            .map(|row| (row.assignment.clone(), row.result()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            results,
+            [
+                (vec![true, true], true),
+                (vec![true, false], false),
+                (vec![false, true], false),
+                (vec![false, false], false),
+            ]
+        );
+
+        let expr = parser.parse("a & a").unwrap();
+        let table = TruthTable::new(&expr).unwrap();
+        assert_eq!(table.variables(), ["a"]);
+        assert_eq!(table.columns().len(), 1);
+
+        let expr = parser.parse("a").unwrap();
+        let table = TruthTable::new(&expr).unwrap();
+        assert_eq!(table.columns().len(), 1);
+        assert_eq!(table.rows().len(), 2);
+    }
+
+    #[test]
+    fn test_truth_table_definitions() {
+        let parser = Parser::new();
+        let mut interpreter = Interpreter::new();
+
+        let mut tokens = TokenStream::parse("p := a & b").unwrap();
+        let definition = parser.parse_definition(&mut tokens).unwrap();
+        interpreter.define(&definition).unwrap();
+
+        let expr = parser.parse("p | c").unwrap();
+        let table = TruthTable::with_interpreter(&interpreter, &expr).unwrap();
+
+        assert_eq!(table.variables(), ["a", "b", "c"]);
+
+        let columns = table
+            .columns()
+            .iter()
+            .map(|column| column.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, ["a & b", "p", "p | c"]);
+        assert_eq!(table.rows().len(), 8);
+        assert!(!table.rows()[7].result());
+    }
+}
@@ -1,4 +1,12 @@
-use crate::{Expression, Tableau};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, RwLock,
+    },
+};
+
+use crate::{Expression, Solutions, Tableau, TruthTable};
 
 pub trait ExpressionWriter {
     type Error;
@@ -11,3 +19,57 @@ pub trait TableauWriter {
 
     fn write_tableau(&mut self, tableau: &Tableau) -> Result<(), Self::Error>;
 }
+
+pub trait TruthTableWriter {
+    type Error;
+
+    fn write_truth_table(&mut self, table: &TruthTable) -> Result<(), Self::Error>;
+}
+
+pub trait SolutionsWriter {
+    type Error;
+
+    fn write_solutions(&mut self, solutions: &Solutions) -> Result<(), Self::Error>;
+}
+
+#[derive(Debug)]
+struct IdMapInner {
+    next_id: AtomicU32,
+    id_map: RwLock<HashMap<u32, u32>>,
+}
+
+impl Default for IdMapInner {
+    fn default() -> Self {
+        Self {
+            next_id: AtomicU32::new(1),
+            id_map: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+/// Maps [`Expectation`](crate::Expectation) ids to consecutive numbers in
+/// the order they are first written.
+///
+/// Clones share the same numbering. Passing clones of one map to the
+/// `share_ids` method of several tableau writers, e.g. one per step of a
+/// traced tableau, keeps every expectation at the same number in all of
+/// them.
+#[derive(Clone, Debug, Default)]
+pub struct SharedIdMap {
+    inner: Arc<IdMapInner>,
+}
+
+impl SharedIdMap {
+    pub fn get_id(&self, id: u32) -> u32 {
+        let mut id_map = self.inner.id_map.write().unwrap();
+
+        if let Some(&new_id) = id_map.get(&id) {
+            return new_id;
+        }
+
+        let new_id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        id_map.insert(id, new_id);
+
+        new_id
+    }
+}